@@ -0,0 +1,88 @@
+//! Named algebras built by Cayley-Dickson construction
+
+use core::ops::Neg;
+use idem::Zero;
+use typenum::consts::P1;
+use typenum::int::Z0;
+
+use { Complex, SelfConjugate, from_rect };
+
+/// Split-complex numbers: `j² = 1`
+pub type SplitComplex<A> = Complex<A, P1>;
+
+/// Dual numbers: `ε² = 0`
+pub type Dual<A> = Complex<A, Z0>;
+
+/// Quaternions: `i² = j² = k² = ijk = -1`
+pub type Quaternion<A> = Complex<Complex<A>>;
+
+/// Octonions
+pub type Octonion<A> = Complex<Quaternion<A>>;
+
+/// Sedenions
+pub type Sedenion<A> = Complex<Octonion<A>>;
+
+/// Coquaternions, or split-quaternions: `i² = -1`, `j² = k² = 1`
+pub type Coquaternion<A> = Complex<Complex<A>, P1>;
+
+/// Synonym of `Coquaternion`
+pub type SplitQuaternion<A> = Coquaternion<A>;
+
+/// Split-octonions
+pub type SplitOctonion<A> = Complex<Quaternion<A>, P1>;
+
+/// Dual complex numbers, where `ε` commutes with the complex part
+pub type DualComplex<A> = Dual<SelfConjugate<Complex<A>>>;
+
+/// Dual quaternions, i.e. quaternions over the dual numbers, so `ε` commutes with the quaternion part
+pub type DualQuaternion<A> = Quaternion<SelfConjugate<Dual<A>>>;
+
+/// `a + bj`
+#[inline] pub const fn split_complex<A>(a: A, b: A) -> SplitComplex<A> { from_rect(a, b) }
+
+/// `a + bε`
+#[inline] pub const fn dual<A: Zero>(a: A, b: A) -> Dual<A> { from_rect(a, b) }
+
+/// `w + xi + yj + zk`
+#[inline] pub const fn quaternion<A: Neg<Output = A>>(w: A, x: A, y: A, z: A) -> Quaternion<A> {
+    from_rect(from_rect(w, x), from_rect(y, z))
+}
+
+/// `a₀e₀ + … + a₇e₇`
+#[allow(clippy::too_many_arguments)]
+#[inline] pub const fn octonion<A: Neg<Output = A>>(a0: A, a1: A, a2: A, a3: A, a4: A, a5: A, a6: A, a7: A) -> Octonion<A> {
+    from_rect(quaternion(a0, a1, a2, a3), quaternion(a4, a5, a6, a7))
+}
+
+/// `a₀e₀ + … + a₁₅e₁₅`
+#[allow(clippy::too_many_arguments)]
+#[inline] pub const fn sedenion<A: Neg<Output = A>>(a0: A, a1: A, a2: A, a3: A, a4: A, a5: A, a6: A, a7: A,
+                                                    a8: A, a9: A, a10: A, a11: A, a12: A, a13: A, a14: A, a15: A) -> Sedenion<A> {
+    from_rect(octonion(a0, a1, a2, a3, a4, a5, a6, a7), octonion(a8, a9, a10, a11, a12, a13, a14, a15))
+}
+
+/// `w + xi + yj + zk`
+#[inline] pub const fn coquaternion<A: Neg<Output = A>>(w: A, x: A, y: A, z: A) -> Coquaternion<A> {
+    from_rect(from_rect(w, x), from_rect(y, z))
+}
+
+/// `a₀e₀ + … + a₇e₇`
+#[allow(clippy::too_many_arguments)]
+#[inline] pub const fn split_octonion<A: Neg<Output = A>>(a0: A, a1: A, a2: A, a3: A, a4: A, a5: A, a6: A, a7: A) -> SplitOctonion<A> {
+    from_rect(quaternion(a0, a1, a2, a3), quaternion(a4, a5, a6, a7))
+}
+
+/// `re + du ε`
+#[inline] pub const fn dual_complex<A: Zero + Neg<Output = A>>(re: Complex<A>, du: Complex<A>) -> DualComplex<A> {
+    from_rect(SelfConjugate(re), SelfConjugate(du))
+}
+
+/// `re + du ε`
+#[inline] pub fn dual_quaternion<A: Zero + Neg<Output = A>>(re: Quaternion<A>, du: Quaternion<A>) -> DualQuaternion<A> {
+    let (a, b) = re.into_rect();
+    let ((w, x), (y, z)) = (a.into_rect(), b.into_rect());
+    let (c, d) = du.into_rect();
+    let ((dw, dx), (dy, dz)) = (c.into_rect(), d.into_rect());
+    quaternion(SelfConjugate(dual(w, dw)), SelfConjugate(dual(x, dx)),
+               SelfConjugate(dual(y, dy)), SelfConjugate(dual(z, dz)))
+}
@@ -0,0 +1,191 @@
+//! Complex arithmetic with the special values of C99 Annex G, "IEC 60559-compatible complex arithmetic"
+//!
+//! Annex G takes a complex number with an infinite component to be infinite, even if the other component is NaN, so that e.g. infinity times any non-zero number is infinite, where the plain formulas give NaN.
+//! The elementary functions follow the special-value tables of G.6, and their signed zeros pick the sides of the branch cuts.
+//! Where Annex G leaves the sign of a zero or infinity unspecified, the sign given here is documented by the tests.
+
+use core::ops::*;
+
+use { Complex, Elementary, Float, Hypercomplex, from_rect };
+
+/// Complex number with the special-value semantics of C99 Annex G
+///
+/// Only `Mul`, `Div` and the `Elementary` functions differ from those of the inner type.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnnexG<A>(pub A);
+
+impl<A> From<A> for AnnexG<A> {
+    #[inline] fn from(a: A) -> Self { AnnexG(a) }
+}
+
+impl<A: Hypercomplex> Hypercomplex for AnnexG<A> {
+    type Scalar = A::Scalar;
+    const DIM: usize = A::DIM;
+
+    #[inline] fn component(&self, i: usize) -> &A::Scalar { self.0.component(i) }
+    #[inline] fn component_mut(&mut self, i: usize) -> &mut A::Scalar { self.0.component_mut(i) }
+    #[inline] fn components(&self) -> impl Iterator<Item = &A::Scalar> { self.0.components() }
+    #[inline] fn components_mut(&mut self) -> impl Iterator<Item = &mut A::Scalar> { self.0.components_mut() }
+    #[inline] fn from_components<I: Iterator<Item = A::Scalar>>(it: &mut I) -> Option<Self> {
+        A::from_components(it).map(AnnexG)
+    }
+}
+
+macro_rules! impl_AnnexG_binop {
+    ($($tr: ident, $f: ident);*) => ($(
+        impl<A: $tr<Output = A>> $tr for AnnexG<A> {
+            type Output = Self;
+            #[inline] fn $f(self, AnnexG(b): Self) -> Self { AnnexG(self.0.$f(b)) }
+        }
+    )*);
+}
+impl_AnnexG_binop!(Add, add; Sub, sub);
+
+impl<A: Neg<Output = A>> Neg for AnnexG<A> {
+    type Output = Self;
+    #[inline] fn neg(self) -> Self { AnnexG(self.0.neg()) }
+}
+
+/// `±1` if `t` is infinite, else `±0`, with the sign of `t`
+#[inline]
+fn unit_if_inf<F: Float>(t: F) -> F { if t.is_infinite() { F::one } else { F::zero }.copysign(t) }
+
+/// `±0` if `t` is NaN, else `t`
+#[inline]
+fn zero_if_nan<F: Float>(t: F) -> F { if t.is_nan() { F::zero.copysign(t) } else { t } }
+
+/// After G.5.1 example 1, `_Cmultd`
+impl<F: Float> Mul for AnnexG<Complex<F>> {
+    type Output = Self;
+    fn mul(self, AnnexG(w): Self) -> Self {
+        let ((mut a, mut b), (mut c, mut d)) = (self.0.into_rect(), w.into_rect());
+        let (ac, bd, ad, bc) = (a * c, b * d, a * d, b * c);
+        let (x, y) = (ac - bd, ad + bc);
+        if !(x.is_nan() && y.is_nan()) { return AnnexG(from_rect(x, y)) }
+
+        let mut recalc = false;
+        if a.is_infinite() || b.is_infinite() {
+            (a, b, c, d) = (unit_if_inf(a), unit_if_inf(b), zero_if_nan(c), zero_if_nan(d));
+            recalc = true;
+        }
+        if c.is_infinite() || d.is_infinite() {
+            (a, b, c, d) = (zero_if_nan(a), zero_if_nan(b), unit_if_inf(c), unit_if_inf(d));
+            recalc = true;
+        }
+        if !recalc && (ac.is_infinite() || bd.is_infinite() || ad.is_infinite() || bc.is_infinite()) {
+            (a, b, c, d) = (zero_if_nan(a), zero_if_nan(b), zero_if_nan(c), zero_if_nan(d));
+            recalc = true;
+        }
+        AnnexG(if recalc { from_rect(F::INFINITY * (a * c - b * d), F::INFINITY * (a * d + b * c)) } else { from_rect(x, y) })
+    }
+}
+
+/// After G.5.1 example 2, `_Cdivd`, but with the robust quotient of `Complex<F>` in place of the scaled textbook formula
+impl<F: Float> Div for AnnexG<Complex<F>> where Complex<F>: Div<Output = Complex<F>> {
+    type Output = Self;
+    fn div(self, AnnexG(w): Self) -> Self {
+        let ((&a, &b), (&c, &d)) = (self.0.as_rect(), w.as_rect());
+        let (x, y) = (self.0 / w).into_rect();
+        if !(x.is_nan() && y.is_nan()) { return AnnexG(from_rect(x, y)) }
+
+        AnnexG(if c == F::zero && d == F::zero && (!a.is_nan() || !b.is_nan()) {
+            let k = F::INFINITY.copysign(c);
+            from_rect(k * a, k * b)
+        } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+            let (a, b) = (unit_if_inf(a), unit_if_inf(b));
+            from_rect(F::INFINITY * (a * c + b * d), F::INFINITY * (b * c - a * d))
+        } else if (c.is_infinite() || d.is_infinite()) && a.is_finite() && b.is_finite() {
+            let (c, d) = (unit_if_inf(c), unit_if_inf(d));
+            from_rect(F::zero * (a * c + b * d), F::zero * (b * c - a * d))
+        } else { from_rect(x, y) })
+    }
+}
+
+/// G.6.3.1
+fn exp<F: Float>(x: F, y: F) -> (F, F) {
+    if y == F::zero { (x.exp(), y) }
+    else if x.is_infinite() && !y.is_finite() {
+        if x > F::zero { (x, F::NAN) } else { (F::zero, F::zero) }
+    } else {
+        let e = x.exp();
+        if e.is_infinite() && x.is_finite() {
+            // `e^x` overflows, but `e^x cos y` may not.
+            let h = (x / (F::one + F::one)).exp();
+            (h * y.cos() * h, h * y.sin() * h)
+        } else { (e * y.cos(), e * y.sin()) }
+    }
+}
+
+/// G.6.3.2
+#[inline]
+fn ln<F: Float>(x: F, y: F) -> (F, F) { (x.hypot(y).ln(), y.atan2(x)) }
+
+/// G.6.4.2
+fn sqrt<F: Float>(x: F, y: F) -> (F, F) {
+    let half = F::one / (F::one + F::one);
+    if x == F::zero && y == F::zero { (F::zero, y) }
+    else if y.is_infinite() { (F::INFINITY, y) }
+    else if x.is_nan() { (x, x) }
+    else if x.is_infinite() {
+        if x > F::zero { (x, if y.is_nan() { y } else { F::zero.copysign(y) }) }
+        else { (if y.is_nan() { y } else { F::zero }, F::INFINITY.copysign(y)) }
+    }
+    else if y.is_nan() { (y, y) }
+    else {
+        // Scale by 1/4 where `|z|` overflows, and the root by 2 to undo it.
+        let (x, y, k) = if x.hypot(y).is_infinite() { (x * half * half, y * half * half, F::one + F::one) } else { (x, y, F::one) };
+        let r = x.hypot(y);
+        if x >= F::zero {
+            let t = (half * r + half * x).sqrt();
+            (k * t, k * (half * y / t))
+        } else {
+            let t = (half * r - half * x).sqrt();
+            (k * (half * y.abs() / t), k * t.copysign(y))
+        }
+    }
+}
+
+/// G.6.2.5
+fn sinh<F: Float>(x: F, y: F) -> (F, F) {
+    if y == F::zero { (x.sinh(), y) }
+    else if (x == F::zero || x.is_infinite()) && !y.is_finite() { (x, F::NAN) }
+    else { (x.sinh() * y.cos(), x.cosh() * y.sin()) }
+}
+
+/// G.6.2.4
+fn cosh<F: Float>(x: F, y: F) -> (F, F) {
+    if y == F::zero { (x.cosh(), y * F::one.copysign(x)) }
+    else if x == F::zero && !y.is_finite() { (F::NAN, x) }
+    else if x.is_infinite() && !y.is_finite() { (F::INFINITY, F::NAN) }
+    else { (x.cosh() * y.cos(), x.sinh() * y.sin()) }
+}
+
+#[inline]
+fn apply<F: Float>(z: Complex<F>, f: fn(F, F) -> (F, F)) -> AnnexG<Complex<F>> {
+    let (x, y) = z.into_rect();
+    let (p, q) = f(x, y);
+    AnnexG(from_rect(p, q))
+}
+
+/// `sin z = -i sinh(i z)` and `cos z = cosh(i z)`, as G.6 defines them; `powf` is `exp(p ln z)`
+impl<F: Float> Elementary for AnnexG<Complex<F>> where Complex<F>: Hypercomplex<Scalar = F> {
+    #[inline] fn exp(self) -> Self { apply(self.0, exp) }
+    #[inline] fn ln(self) -> Self { apply(self.0, ln) }
+    #[inline] fn sqrt(self) -> Self { apply(self.0, sqrt) }
+    #[inline] fn powf(self, p: F) -> Self {
+        let (x, y) = ln(*self.0.re(), *self.0.im());
+        apply(from_rect(p * x, p * y), exp)
+    }
+    #[inline] fn sin(self) -> Self {
+        let (x, y) = self.0.into_rect();
+        let (p, q) = sinh(-y, x);
+        AnnexG(from_rect(q, -p))
+    }
+    #[inline] fn cos(self) -> Self {
+        let (x, y) = self.0.into_rect();
+        apply(from_rect(-y, x), cosh)
+    }
+    #[inline] fn sinh(self) -> Self { apply(self.0, sinh) }
+    #[inline] fn cosh(self) -> Self { apply(self.0, cosh) }
+}
@@ -0,0 +1,104 @@
+//! Byte encodings, in little- or big-endian order, as the components in canonical basis order
+
+use core::convert::TryInto;
+use core::fmt;
+
+use { Complex, Hypercomplex, Sign };
+
+/// Error encoding into or decoding from a byte buffer of the wrong length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByteLengthError;
+
+impl fmt::Display for ByteLengthError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result { fmt.write_str("byte length does not match number of values") }
+}
+
+/// A base scalar with a byte encoding of fixed size
+pub trait ScalarBytes: Sized {
+    const SIZE: usize;
+
+    /// Write the encoding into `out`, which is `SIZE` bytes long
+    fn write_le(&self, out: &mut [u8]);
+    fn write_be(&self, out: &mut [u8]);
+
+    /// Read the encoding from `bytes`, which is `SIZE` bytes long
+    fn read_le(bytes: &[u8]) -> Self;
+    fn read_be(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_ScalarBytes {
+    ($($t: ty),*) => ($(impl ScalarBytes for $t {
+        const SIZE: usize = ::core::mem::size_of::<$t>();
+
+        #[inline] fn write_le(&self, out: &mut [u8]) { out.copy_from_slice(&self.to_le_bytes()) }
+        #[inline] fn write_be(&self, out: &mut [u8]) { out.copy_from_slice(&self.to_be_bytes()) }
+        #[inline] fn read_le(bytes: &[u8]) -> $t { <$t>::from_le_bytes(bytes.try_into().expect("wrong scalar size")) }
+        #[inline] fn read_be(bytes: &[u8]) -> $t { <$t>::from_be_bytes(bytes.try_into().expect("wrong scalar size")) }
+    })*);
+}
+impl_ScalarBytes!(f32, f64, isize, i8, i16, i32, i64);
+
+type Scalar<T> = <T as Hypercomplex>::Scalar;
+
+impl<S: Sign<A>, A> Complex<A, S> where Self: Hypercomplex, Scalar<Self>: ScalarBytes {
+    /// Length of the encoding, `DIM` times that of the scalar
+    pub const BYTES: usize = Self::DIM * Scalar::<Self>::SIZE;
+
+    /// The array length `N` must be `BYTES`, e.g. `let b: [u8; 8] = z.to_le_bytes()` for `z: Complex<f32>`; else this fails to compile.
+    #[inline]
+    pub fn to_le_bytes<const N: usize>(&self) -> [u8; N] { self.to_bytes(ScalarBytes::write_le) }
+    #[inline]
+    pub fn to_be_bytes<const N: usize>(&self) -> [u8; N] { self.to_bytes(ScalarBytes::write_be) }
+    #[inline]
+    pub fn from_le_bytes<const N: usize>(bytes: [u8; N]) -> Self { Self::from_bytes(bytes, ScalarBytes::read_le) }
+    #[inline]
+    pub fn from_be_bytes<const N: usize>(bytes: [u8; N]) -> Self { Self::from_bytes(bytes, ScalarBytes::read_be) }
+
+    /// Encode `xs` into `out`, which must be `xs.len() * BYTES` long
+    #[inline]
+    pub fn write_le_bytes(xs: &[Self], out: &mut [u8]) -> Result<(), ByteLengthError> { Self::write_all(xs, out, ScalarBytes::write_le) }
+    #[inline]
+    pub fn write_be_bytes(xs: &[Self], out: &mut [u8]) -> Result<(), ByteLengthError> { Self::write_all(xs, out, ScalarBytes::write_be) }
+
+    /// Decode `bytes`, which must be `out.len() * BYTES` long, into `out`
+    #[inline]
+    pub fn read_le_bytes(bytes: &[u8], out: &mut [Self]) -> Result<(), ByteLengthError> { Self::read_all(bytes, out, ScalarBytes::read_le) }
+    #[inline]
+    pub fn read_be_bytes(bytes: &[u8], out: &mut [Self]) -> Result<(), ByteLengthError> { Self::read_all(bytes, out, ScalarBytes::read_be) }
+
+    #[inline]
+    fn to_bytes<const N: usize>(&self, f: fn(&Scalar<Self>, &mut [u8])) -> [u8; N] {
+        const { assert!(N == Self::BYTES, "array length is not `Complex::BYTES`") };
+        let mut bytes = [0; N];
+        self.encode(&mut bytes, f);
+        bytes
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut [u8], f: fn(&Scalar<Self>, &mut [u8])) {
+        for (c, out) in self.components().zip(out.chunks_exact_mut(Scalar::<Self>::SIZE)) { f(c, out) }
+    }
+
+    #[inline]
+    fn from_bytes<const N: usize>(bytes: [u8; N], f: fn(&[u8]) -> Scalar<Self>) -> Self {
+        const { assert!(N == Self::BYTES, "array length is not `Complex::BYTES`") };
+        Self::decode(&bytes, f)
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8], f: fn(&[u8]) -> Scalar<Self>) -> Self {
+        Self::from_components(&mut bytes.chunks_exact(Scalar::<Self>::SIZE).map(f)).expect("too few components")
+    }
+
+    fn write_all(xs: &[Self], out: &mut [u8], f: fn(&Scalar<Self>, &mut [u8])) -> Result<(), ByteLengthError> {
+        if out.len() != xs.len() * Self::BYTES { return Err(ByteLengthError) }
+        for (x, out) in xs.iter().zip(out.chunks_exact_mut(Self::BYTES)) { x.encode(out, f) }
+        Ok(())
+    }
+
+    fn read_all(bytes: &[u8], out: &mut [Self], f: fn(&[u8]) -> Scalar<Self>) -> Result<(), ByteLengthError> {
+        if bytes.len() != out.len() * Self::BYTES { return Err(ByteLengthError) }
+        for (bytes, x) in bytes.chunks_exact(Self::BYTES).zip(out) { *x = Self::decode(bytes, f) }
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+//! Formatting with basis labels
+//!
+//! `Display` and `LowerExp` write every component followed by its basis label, e.g. `1+2i` or `1-2i+3j+0.5k`.
+//! The default labels follow the nesting, from the innermost level, by `Hypercomplex::unit_square`: a unit squaring to -1 is `i`, to 1 is `j` and to 0 is `ε`, save that a unit squaring to -1 and the next, squaring to ±1, are `i` and `j`, with `k` their product, as in the quaternions and coquaternions.
+//! Other units are the products of these, e.g. `1+2i+3ε+4iε` for a dual complex number, and the real part has none.
+//! Where this would name two units alike, as in the octonions, the labels are `e0`, `e1`, … as in `CayleyTable`.
+//! Where a label could be read as part of the number before it, e.g. `e1` after `2`, a `*` separates them: `1*e0+2*e1`.
+//!
+//! Precision and the `+` flag apply to each component, and width, fill and alignment to the whole.
+
+use core::fmt::{ self, Alignment, Write };
+
+use table::Label;
+use { Complex, Hypercomplex, Sign };
+
+/// A value to format with given basis labels
+///
+/// Labels past the end of `labels` are the defaults.
+pub struct Labelled<'a, T: 'a> { value: &'a T, labels: &'a [&'a str] }
+
+impl<S: Sign<A>, A> Complex<A, S> {
+    /// Format with the given basis labels, the first for the real part.
+    #[inline] pub fn labelled<'a>(&'a self, labels: &'a [&'a str]) -> Labelled<'a, Self> { Labelled { value: self, labels } }
+}
+
+impl<S: Sign<A>, A: fmt::Debug> fmt::Debug for Complex<A, S> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (a, b) = self.as_rect();
+        fmt.debug_tuple("").field(a).field(b).finish()
+    }
+}
+
+impl<S: Sign<A>, A> fmt::Display for Complex<A, S> where Self: Hypercomplex, <Self as Hypercomplex>::Scalar: fmt::Display {
+    #[inline] fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(&self.labelled(&[]), fmt) }
+}
+
+impl<S: Sign<A>, A> fmt::LowerExp for Complex<A, S> where Self: Hypercomplex, <Self as Hypercomplex>::Scalar: fmt::LowerExp {
+    #[inline] fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result { fmt::LowerExp::fmt(&self.labelled(&[]), fmt) }
+}
+
+impl<'a, T: Hypercomplex> fmt::Display for Labelled<'a, T> where T::Scalar: fmt::Display {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write_padded(fmt, self, |w, c, plus, precision| match (plus, precision) {
+            (false, None) => write!(w, "{}", c),
+            (false, Some(p)) => write!(w, "{:.*}", p, c),
+            (true, None) => write!(w, "{:+}", c),
+            (true, Some(p)) => write!(w, "{:+.*}", p, c),
+        })
+    }
+}
+
+impl<'a, T: Hypercomplex> fmt::LowerExp for Labelled<'a, T> where T::Scalar: fmt::LowerExp {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write_padded(fmt, self, |w, c, plus, precision| match (plus, precision) {
+            (false, None) => write!(w, "{:e}", c),
+            (false, Some(p)) => write!(w, "{:.*e}", p, c),
+            (true, None) => write!(w, "{:+e}", c),
+            (true, Some(p)) => write!(w, "{:+.*e}", p, c),
+        })
+    }
+}
+
+/// Component formatter, given whether to force the sign, and the precision
+type WriteScalar<A> = fn(&mut dyn Write, &A, bool, Option<usize>) -> fmt::Result;
+
+/// The default label of `e_n` in `T`
+pub(crate) fn default_label<T: Hypercomplex>(n: usize) -> Label {
+    const NAMES: [&str; 4] = ["i", "j", "k", "ε"];
+    let levels = T::DIM.trailing_zeros() as usize;
+    let (mut label, mut used, mut level) = (Label::new(), 0, 0);
+    while level < levels {
+        // The names of the units of this level and maybe the next, as indices into `NAMES`
+        let names: &[usize] = match T::unit_square(level) {
+            -1 if level + 1 < levels && T::unit_square(level + 1) != 0 => &[0, 1, 2],
+            -1 => &[0],
+            1 => &[1],
+            _ => &[3],
+        };
+        if names.iter().any(|&k| used & 1 << k != 0) { return Label::new().push_basis(n) }
+        used |= names.iter().fold(0, |m, &k| m | 1 << k);
+        let width = names.len().div_ceil(2);
+        let v = n >> level & ((1 << width) - 1);
+        if v != 0 { label = label.push_str(NAMES[names[v - 1]]); }
+        level += width;
+    }
+    label
+}
+
+/// Whether `label` could be read as part of a number written just before it
+pub(crate) fn needs_separator(label: &str) -> bool {
+    label.starts_with(|c: char| c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E')
+}
+
+fn write_components<T: Hypercomplex>(w: &mut dyn Write, x: &Labelled<T>, plus: bool, precision: Option<usize>,
+                                      f: WriteScalar<T::Scalar>) -> fmt::Result {
+    for (n, c) in x.value.components().enumerate() {
+        if plus || n > 0 { f(&mut Signed(w, false), c, true, precision)?; } else { f(w, c, false, precision)?; }
+        let default = default_label::<T>(n);
+        let label = x.labels.get(n).cloned().unwrap_or(&default);
+        if needs_separator(label) { w.write_char('*')?; }
+        w.write_str(label)?;
+    }
+    Ok(())
+}
+
+/// Writer that makes sure its output starts with a sign, as `NaN` has none even with `+`
+struct Signed<'a>(&'a mut dyn Write, bool);
+
+impl<'a> Write for Signed<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.1 && !s.is_empty() {
+            self.1 = true;
+            if !s.starts_with(['+', '-']) { self.0.write_char('+')?; }
+        }
+        self.0.write_str(s)
+    }
+}
+
+/// Characters written, to pad without an allocator
+struct Count(usize);
+
+impl Write for Count {
+    #[inline] fn write_str(&mut self, s: &str) -> fmt::Result { self.0 += s.chars().count(); Ok(()) }
+}
+
+fn write_padded<T: Hypercomplex>(fmt: &mut fmt::Formatter, x: &Labelled<T>, f: WriteScalar<T::Scalar>) -> fmt::Result {
+    let (plus, precision) = (fmt.sign_plus(), fmt.precision());
+    let width = match fmt.width() {
+        None => return write_components(fmt, x, plus, precision, f),
+        Some(width) => width,
+    };
+    let mut count = Count(0);
+    write_components(&mut count, x, plus, precision, f)?;
+    let pad = width.saturating_sub(count.0);
+    let (before, after) = match fmt.align() {
+        Some(Alignment::Left) => (0, pad),
+        Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+        Some(Alignment::Right) | None => (pad, 0),
+    };
+    let fill = fmt.fill();
+    for _ in 0..before { fmt.write_char(fill)?; }
+    write_components(fmt, x, plus, precision, f)?;
+    for _ in 0..after { fmt.write_char(fill)?; }
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! Division of complex numbers, robust over floating-point scalars
+//!
+//! The exact formula `x y* / (y y*)` overflows where `y y*` does, e.g. for components near `1e200` in `f64`, though the quotient is representable.
+//! Here instead is the robust algorithm of Baudin and Smith (2012), extended to split-complex and dual numbers: with `y = c + d u` and `u² = s`,
+//!
+//! `(a + b u) / (c + d u) = ((a - s b r) + (b - a r) u) / (c - s d r)`, where `r = d/c`, if `|c| ≥ |d|`,
+//!
+//! and likewise with the roles of `c` and `d` swapped otherwise, after scaling the operands away from overflow and underflow.
+//! Dividing by `c - s d r`, rather than multiplying by its reciprocal, keeps precision where the reciprocal is subnormal.
+
+use core::any::Any;
+use core::ops::*;
+
+use { Complex, Conjugable, Sign, from_rect };
+
+/// `x / y`, the right quotient `x y⁻¹`
+///
+/// This is by the robust algorithm where the base scalar is `f32` or `f64`, as told by its `TypeId`, and otherwise by `x y* / (y y*)`, which is exact for exact scalars.
+pub(crate) fn div<S: Sign<A>, A>(x: Complex<A, S>, y: Complex<A, S>) -> Complex<A, S>
+  where A: 'static + Clone + Add<Output = A> + Neg<Output = A> + Conjugable + Mul<Output = A> + Div<Output = A> {
+    let (xr, yr) = (x.as_rect(), y.as_rect());
+    let s = S::I8;
+    if let Some((e, f)) = float(xr, yr, |x, y| div_f32(x, y, s as f32)).or_else(|| float(xr, yr, |x, y| div_f64(x, y, s as f64))) {
+        return from_rect(e, f)
+    }
+    let (a, b) = (x*y.clone().conjugate()).into_rect();
+    let (c, _) = (y.clone()*y.conjugate()).into_rect();
+    from_rect(a/c.clone(), b/c)
+}
+
+/// `f(x, y)`, if `A` is `F`
+fn float<A: 'static, F: 'static + Copy>((a, b): (&A, &A), (c, d): (&A, &A), f: impl FnOnce((F, F), (F, F)) -> (F, F)) -> Option<(A, A)> {
+    let get = |a: &A| (a as &dyn Any).downcast_ref::<F>().cloned();
+    let mut q = Some(f((get(a)?, get(b)?), (get(c)?, get(d)?)));
+    (&mut q as &mut dyn Any).downcast_mut::<Option<(A, A)>>()?.take()
+}
+
+/// `(a + b u) / (c + d u)`, where `u² = s`
+macro_rules! def_div_float {
+    ($($f: ident: $t: ident),*) => ($(
+        fn $f((mut a, mut b): ($t, $t), (mut c, mut d): ($t, $t), s: $t) -> ($t, $t) {
+            let (ab, cd) = (a.abs().max(b.abs()), c.abs().max(d.abs()));
+
+            // Scale to keep the intermediate values in range; `k` undoes it.
+            let half_eps = $t::EPSILON / 2.;
+            let be = 2. / (half_eps * half_eps);
+            let mut k = 1.;
+            if ab >= $t::MAX / 2. { a /= 2.; b /= 2.; k *= 2.; }
+            if cd >= $t::MAX / 2. { c /= 2.; d /= 2.; k /= 2.; }
+            if ab <= $t::MIN_POSITIVE * 2. / half_eps { a *= be; b *= be; k /= be; }
+            if cd <= $t::MIN_POSITIVE * 2. / half_eps { c *= be; d *= be; k *= be; }
+
+            // `(p + q r) / t`, where `r = n/m`, guarding against `q r` underflowing
+            let comp = |p: $t, q: $t, r: $t, t: $t, n: $t, m: $t| if r != 0. {
+                let qr = q * r;
+                if qr != 0. { (p + qr) / t } else { p / t + (q / t) * r }
+            } else { (p + n * (q / m)) / t };
+
+            let (e, f) = if c.abs() >= d.abs() {
+                let r = d / c;
+                let t = c - s * d * r;
+                (comp(a, -s * b, r, t, d, c), comp(b, -a, r, t, d, c))
+            } else {
+                let r = c / d;
+                let t = c * r - s * d;
+                (comp(-s * b, a, r, t, c, d), comp(-a, b, r, t, c, d))
+            };
+            (e * k, f * k)
+        }
+    )*);
+}
+def_div_float!(div_f32: f32, div_f64: f64);
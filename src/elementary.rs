@@ -0,0 +1,162 @@
+//! Elementary functions by the scalar-plus-vector decomposition
+//!
+//! Write `x = a + v`, with `a` the scalar part and `v` the vector part. In any Cayley-Dickson algebra `v² = -N(v)`, where `N` is the quadratic form `Norm::norm_sqr`, so `a + v` lies in a commutative plane like one of these:
+//!
+//! * `N(v) > 0`: the complex plane, with `v = s·u`, `u² = -1`; e.g. in quaternions and octonions
+//! * `N(v) < 0`: the split-complex plane, with `v = s·u`, `u² = 1`, where `cosh` and `sinh` take the place of `cos` and `sin`
+//! * `N(v) = 0`: the dual plane, where `f(a + v) = f(a) + f'(a) v`
+//!
+//! If `v` is zero and `e1² = -1`, `x` is taken to lie in the plane of `e1`, and the sign of its `e1` component picks the side of any branch cut, as for complex numbers.
+//! If `v` is infinite, it lies along its infinite components, so e.g. `ln(1 + ∞i) = ∞ + (π/2)i`; if that direction is null, or `v` has a NaN component, the result is NaN.
+
+use core::ops::Mul;
+
+use { Complex, Float, Hypercomplex, Norm, Sign };
+
+/// Which plane `a + v` lies in, and `s`, where `v = s·u`
+enum Plane<F> { Elliptic(F), Hyperbolic(F), Parabolic }
+
+use self::Plane::*;
+
+/// Elementary functions, for algebras over a `Float`
+///
+/// This is for `Complex`, not for the scalars themselves, so as not to make e.g. `f64::sqrt` ambiguous with `Float::sqrt`.
+///
+/// Domains are those of the real functions in each plane: e.g. `ln` of a split-complex number is defined only inside the right-hand light cone.
+pub trait Elementary: Hypercomplex {
+    fn exp(self) -> Self;
+    /// Principal natural logarithm
+    fn ln(self) -> Self;
+    /// Principal square root
+    fn sqrt(self) -> Self;
+    /// `exp(p ln(x))`
+    fn powf(self, p: Self::Scalar) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+}
+
+/// Apply `f` to `x` in its plane, where `f(a, Elliptic(s)) = (p, q)` means `f(a + s i) = p + q i`, likewise for `Hyperbolic` with `j`, and `f(a, Parabolic) = (f(a), f'(a))`.
+fn apply<T, F: Float>(x: T, f: fn(F, Plane<F>) -> (F, F)) -> T where T: Clone + Hypercomplex<Scalar = F> + Norm<Real = F> + Mul<F, Output = T> {
+    let a = *x.component(0);
+    let mut v = x;
+    *v.component_mut(0) = F::zero;
+
+    let m = v.components().fold(F::zero, |m, c| { let c = c.abs(); if c > m { c } else { m } });
+    let (p, mut y) = if v.components().any(|c| c.is_nan()) {
+        (F::NAN, v * F::NAN)
+    } else if m.is_infinite() {
+        // `v` lies along its infinite components, whose plane is that of their signs.
+        let mut u = v;
+        for c in u.components_mut() { *c = if c.is_infinite() { F::one.copysign(*c) } else { F::zero } }
+        let n = u.clone().norm_sqr();
+        let (p, q) = if n > F::zero { f(a, Elliptic(m)) } else if n < F::zero { f(a, Hyperbolic(m)) } else { (F::NAN, F::NAN) };
+        (p, u * (q / n.abs().sqrt()))
+    } else if m == F::zero {
+        if T::DIM > 1 && T::basis(1).norm_sqr() > F::zero {
+            let (p, q) = f(a, Elliptic(*v.component(1)));
+            *v.component_mut(1) = q;
+            (p, v)
+        } else { (f(a, Parabolic).0, v) }
+    } else {
+        // Scale to keep `N(v)` finite.
+        let n = (v.clone() * (F::one/m)).norm_sqr();
+        if n > F::zero {
+            let s = m * n.sqrt();
+            let (p, q) = f(a, Elliptic(s));
+            (p, v * (q/s))
+        } else if n < F::zero {
+            let s = m * (-n).sqrt();
+            let (p, q) = f(a, Hyperbolic(s));
+            (p, v * (q/s))
+        } else {
+            let (p, d) = f(a, Parabolic);
+            (p, v * d)
+        }
+    };
+    *y.component_mut(0) = p;
+    y
+}
+
+fn exp<F: Float>(a: F, plane: Plane<F>) -> (F, F) {
+    let e = a.exp();
+    match plane {
+        Elliptic(s) => (e * s.cos(), e * s.sin()),
+        Hyperbolic(s) => (e * s.cosh(), e * s.sinh()),
+        Parabolic => (e, e),
+    }
+}
+
+fn ln<F: Float>(a: F, plane: Plane<F>) -> (F, F) {
+    let half = F::one / (F::one + F::one);
+    match plane {
+        Elliptic(s) => (a.hypot(s).ln(), s.atan2(a)),
+        Hyperbolic(s) => (half * ((a + s).ln() + (a - s).ln()), (s / a).atanh()),
+        Parabolic => (a.ln(), F::one / a),
+    }
+}
+
+fn sqrt<F: Float>(a: F, plane: Plane<F>) -> (F, F) {
+    let half = F::one / (F::one + F::one);
+    match plane {
+        Elliptic(s) => {
+            let r = a.hypot(s);
+            if a >= F::zero {
+                let p = (half * (r + a)).sqrt();
+                (p, if p == F::zero { s } else { half * s / p })
+            } else {
+                let q = (half * (r - a)).sqrt();
+                (half * s.abs() / q, q.copysign(s))
+            }
+        },
+        Hyperbolic(s) => {
+            let (u, w) = ((a + s).sqrt(), (a - s).sqrt());
+            (half * (u + w), s / (u + w))
+        },
+        Parabolic => { let r = a.sqrt(); (r, half / r) },
+    }
+}
+
+fn sin<F: Float>(a: F, plane: Plane<F>) -> (F, F) {
+    match plane {
+        Elliptic(s) => (a.sin() * s.cosh(), a.cos() * s.sinh()),
+        Hyperbolic(s) => (a.sin() * s.cos(), a.cos() * s.sin()),
+        Parabolic => (a.sin(), a.cos()),
+    }
+}
+
+fn cos<F: Float>(a: F, plane: Plane<F>) -> (F, F) {
+    match plane {
+        Elliptic(s) => (a.cos() * s.cosh(), -(a.sin() * s.sinh())),
+        Hyperbolic(s) => (a.cos() * s.cos(), -(a.sin() * s.sin())),
+        Parabolic => (a.cos(), -a.sin()),
+    }
+}
+
+fn sinh<F: Float>(a: F, plane: Plane<F>) -> (F, F) {
+    match plane {
+        Elliptic(s) => (a.sinh() * s.cos(), a.cosh() * s.sin()),
+        Hyperbolic(s) => (a.sinh() * s.cosh(), a.cosh() * s.sinh()),
+        Parabolic => (a.sinh(), a.cosh()),
+    }
+}
+
+fn cosh<F: Float>(a: F, plane: Plane<F>) -> (F, F) {
+    match plane {
+        Elliptic(s) => (a.cosh() * s.cos(), a.sinh() * s.sin()),
+        Hyperbolic(s) => (a.cosh() * s.cosh(), a.sinh() * s.sinh()),
+        Parabolic => (a.cosh(), a.sinh()),
+    }
+}
+
+impl<S: Sign<A>, A, F: Float> Elementary for Complex<A, S> where Self: Clone + Hypercomplex<Scalar = F> + Norm<Real = F> + Mul<F, Output = Self> {
+    #[inline] fn exp(self) -> Self { apply(self, exp) }
+    #[inline] fn ln(self) -> Self { apply(self, ln) }
+    #[inline] fn sqrt(self) -> Self { apply(self, sqrt) }
+    #[inline] fn powf(self, p: F) -> Self { (self.ln() * p).exp() }
+    #[inline] fn sin(self) -> Self { apply(self, sin) }
+    #[inline] fn cos(self) -> Self { apply(self, cos) }
+    #[inline] fn sinh(self) -> Self { apply(self, sinh) }
+    #[inline] fn cosh(self) -> Self { apply(self, cosh) }
+}
@@ -0,0 +1,92 @@
+//! Embeddings of lower levels of a construction into higher levels, and the projections back
+//!
+//! `From` embeds with the higher components zero; `TryFrom` projects only if they are zero.
+//! Name the scalar type when using the aliases, e.g. `Quaternion::<f64>::from(z)`, else the level of `z` is ambiguous.
+
+use core::convert::TryFrom;
+use core::fmt;
+use idem::Zero;
+
+use { Complex, Sign, from_rect };
+
+/// Error projecting onto a lower level when some higher component is not zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectionError;
+
+impl fmt::Display for ProjectionError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result { fmt.write_str("higher components not zero") }
+}
+
+#[inline]
+fn project<S: Sign<A>, A: Zero + PartialEq>(x: Complex<A, S>) -> Result<A, ProjectionError> {
+    let (a, b) = x.into_rect();
+    if b == A::zero { Ok(a) } else { Err(ProjectionError) }
+}
+
+impl<S1: Sign<A>, S2: Sign<Complex<A, S1>>, A: Zero> From<A> for Complex<Complex<A, S1>, S2> {
+    #[inline] fn from(a: A) -> Self { from_rect(from_rect(a, A::zero), Zero::zero) }
+}
+
+impl<S1: Sign<A>, S2: Sign<Complex<A, S1>>, S3: Sign<Complex<Complex<A, S1>, S2>>, A: Zero>
+  From<A> for Complex<Complex<Complex<A, S1>, S2>, S3> {
+    #[inline] fn from(a: A) -> Self { from_rect(Complex::<A, S1>::from(a).into(), Zero::zero) }
+}
+
+impl<S1: Sign<A>, S2: Sign<Complex<A, S1>>, S3: Sign<Complex<Complex<A, S1>, S2>>,
+     S4: Sign<Complex<Complex<Complex<A, S1>, S2>, S3>>, A: Zero>
+  From<A> for Complex<Complex<Complex<Complex<A, S1>, S2>, S3>, S4> {
+    #[inline] fn from(a: A) -> Self { from_rect(Complex::<Complex<A, S1>, S2>::from(a).into(), Zero::zero) }
+}
+
+impl<S1: Sign<A>, S2: Sign<Complex<A, S1>>, A: Zero + PartialEq>
+  TryFrom<Complex<Complex<A, S1>, S2>> for Complex<A, S1> {
+    type Error = ProjectionError;
+    #[inline] fn try_from(x: Complex<Complex<A, S1>, S2>) -> Result<Self, ProjectionError> { project(x) }
+}
+
+impl<S1: Sign<A>, S2: Sign<Complex<A, S1>>, S3: Sign<Complex<Complex<A, S1>, S2>>, A: Zero + PartialEq>
+  TryFrom<Complex<Complex<Complex<A, S1>, S2>, S3>> for Complex<A, S1> {
+    type Error = ProjectionError;
+    #[inline] fn try_from(x: Complex<Complex<Complex<A, S1>, S2>, S3>) -> Result<Self, ProjectionError> { project(project(x)?) }
+}
+
+impl<S1: Sign<A>, S2: Sign<Complex<A, S1>>, S3: Sign<Complex<Complex<A, S1>, S2>>,
+     S4: Sign<Complex<Complex<Complex<A, S1>, S2>, S3>>, A: Zero + PartialEq>
+  TryFrom<Complex<Complex<Complex<Complex<A, S1>, S2>, S3>, S4>> for Complex<A, S1> {
+    type Error = ProjectionError;
+    #[inline] fn try_from(x: Complex<Complex<Complex<Complex<A, S1>, S2>, S3>, S4>) -> Result<Self, ProjectionError> {
+        project(project(project(x)?)?)
+    }
+}
+
+macro_rules! impl_TryFrom_scalar {
+    ($($t: ty),*) => ($(
+        impl<S1: Sign<$t>> TryFrom<Complex<$t, S1>> for $t {
+            type Error = ProjectionError;
+            #[inline] fn try_from(x: Complex<$t, S1>) -> Result<Self, ProjectionError> { project(x) }
+        }
+
+        impl<S1: Sign<$t>, S2: Sign<Complex<$t, S1>>> TryFrom<Complex<Complex<$t, S1>, S2>> for $t {
+            type Error = ProjectionError;
+            #[inline] fn try_from(x: Complex<Complex<$t, S1>, S2>) -> Result<Self, ProjectionError> { project(project(x)?) }
+        }
+
+        impl<S1: Sign<$t>, S2: Sign<Complex<$t, S1>>, S3: Sign<Complex<Complex<$t, S1>, S2>>>
+          TryFrom<Complex<Complex<Complex<$t, S1>, S2>, S3>> for $t {
+            type Error = ProjectionError;
+            #[inline] fn try_from(x: Complex<Complex<Complex<$t, S1>, S2>, S3>) -> Result<Self, ProjectionError> {
+                project(project(project(x)?)?)
+            }
+        }
+
+        impl<S1: Sign<$t>, S2: Sign<Complex<$t, S1>>, S3: Sign<Complex<Complex<$t, S1>, S2>>,
+             S4: Sign<Complex<Complex<Complex<$t, S1>, S2>, S3>>>
+          TryFrom<Complex<Complex<Complex<Complex<$t, S1>, S2>, S3>, S4>> for $t {
+            type Error = ProjectionError;
+            #[inline] fn try_from(x: Complex<Complex<Complex<Complex<$t, S1>, S2>, S3>, S4>) -> Result<Self, ProjectionError> {
+                project(project(project(project(x)?)?)?)
+            }
+        }
+    )*);
+}
+impl_TryFrom_scalar!(f32, f64, isize, i8, i16, i32, i64);
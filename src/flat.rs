@@ -0,0 +1,74 @@
+//! Zero-copy views of slices of numbers as slices of their components, and back
+
+use core::mem::{ align_of, size_of };
+use core::slice;
+
+use { AnnexG, Complex, Hypercomplex, SelfConjugate, Sign };
+
+/// A `Hypercomplex` type laid out exactly as an array of its `DIM` scalars, in canonical basis order
+///
+/// # Safety
+///
+/// `Self` must have the size and alignment of `[Self::Scalar; Self::DIM]`, with component `n` at offset `n * size_of::<Self::Scalar>()`, and any `DIM` valid scalars must make a valid `Self`.
+pub unsafe trait Flat: Hypercomplex {}
+
+unsafe impl<S: Sign<A>, A: Flat> Flat for Complex<A, S> {}
+unsafe impl<A: Flat> Flat for SelfConjugate<A> {}
+unsafe impl<A: Flat> Flat for AnnexG<A> {}
+
+macro_rules! impl_Flat_scalar {
+    ($($t: ty),*) => ($(unsafe impl Flat for $t {})*);
+}
+impl_Flat_scalar!(f32, f64, isize, i8, i16, i32, i64);
+
+impl<S: Sign<A>, A> Complex<A, S> where Self: Flat {
+    /// View numbers as their interleaved components, e.g. `[re₀, im₀, re₁, im₁, …]`
+    #[inline]
+    pub fn as_flat(xs: &[Self]) -> &[<Self as Hypercomplex>::Scalar] {
+        Self::assert_flat();
+        unsafe { slice::from_raw_parts(xs.as_ptr() as *const _, xs.len() * Self::DIM) }
+    }
+
+    #[inline]
+    pub fn as_flat_mut(xs: &mut [Self]) -> &mut [<Self as Hypercomplex>::Scalar] {
+        Self::assert_flat();
+        unsafe { slice::from_raw_parts_mut(xs.as_mut_ptr() as *mut _, xs.len() * Self::DIM) }
+    }
+
+    /// View interleaved components as numbers, or `None` if the length is not a multiple of `DIM`
+    #[inline]
+    pub fn from_flat(xs: &[<Self as Hypercomplex>::Scalar]) -> Option<&[Self]> {
+        Self::assert_flat();
+        if 0 != xs.len() % Self::DIM { return None }
+        Some(unsafe { slice::from_raw_parts(xs.as_ptr() as *const Self, xs.len() / Self::DIM) })
+    }
+
+    #[inline]
+    pub fn from_flat_mut(xs: &mut [<Self as Hypercomplex>::Scalar]) -> Option<&mut [Self]> {
+        Self::assert_flat();
+        if 0 != xs.len() % Self::DIM { return None }
+        Some(unsafe { slice::from_raw_parts_mut(xs.as_mut_ptr() as *mut Self, xs.len() / Self::DIM) })
+    }
+
+    #[inline]
+    fn assert_flat() {
+        const {
+            assert!(size_of::<Self>() == Self::DIM * size_of::<<Self as Hypercomplex>::Scalar>());
+            assert!(align_of::<Self>() == align_of::<<Self as Hypercomplex>::Scalar>());
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use bytemuck::{ Pod, Zeroable };
+
+    use { AnnexG, Complex, SelfConjugate, Sign };
+
+    unsafe impl<S: Sign<A>, A: Zeroable> Zeroable for Complex<A, S> {}
+    unsafe impl<S: Sign<A> + 'static, A: Pod> Pod for Complex<A, S> {}
+    unsafe impl<A: Zeroable> Zeroable for SelfConjugate<A> {}
+    unsafe impl<A: Pod> Pod for SelfConjugate<A> {}
+    unsafe impl<A: Zeroable> Zeroable for AnnexG<A> {}
+    unsafe impl<A: Pod> Pod for AnnexG<A> {}
+}
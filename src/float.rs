@@ -0,0 +1,61 @@
+//! Real functions of the base scalars, from `std` or, on `no_std`, from `libm`
+
+use core::ops::*;
+use idem::{ Zero, One };
+
+/// Floating-point base scalar
+///
+/// Implemented for `f32` and `f64` if the `std` or `libm` feature is enabled.
+pub trait Float: Copy + PartialOrd + Zero + One + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self> {
+    const NAN: Self;
+    const INFINITY: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn atanh(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn hypot(self, y: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn is_finite(self) -> bool;
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_Float {
+    ($t: ident; $($f: ident = $g: path),*; $($f2: ident = $g2: path),*) => (impl Float for $t {
+        const NAN: Self = $t::NAN;
+        const INFINITY: Self = $t::INFINITY;
+        $(#[inline] fn $f(self) -> Self { $g(self) })*
+        $(#[inline] fn $f2(self, other: Self) -> Self { $g2(self, other) })*
+        #[inline] fn is_nan(self) -> bool { $t::is_nan(self) }
+        #[inline] fn is_infinite(self) -> bool { $t::is_infinite(self) }
+        #[inline] fn is_finite(self) -> bool { $t::is_finite(self) }
+    });
+}
+
+#[cfg(feature = "std")]
+impl_Float!(f32; abs = f32::abs, sqrt = f32::sqrt, exp = f32::exp, ln = f32::ln, sin = f32::sin, cos = f32::cos,
+                 sinh = f32::sinh, cosh = f32::cosh, atanh = f32::atanh;
+                 atan2 = f32::atan2, hypot = f32::hypot, copysign = f32::copysign);
+
+#[cfg(feature = "std")]
+impl_Float!(f64; abs = f64::abs, sqrt = f64::sqrt, exp = f64::exp, ln = f64::ln, sin = f64::sin, cos = f64::cos,
+                 sinh = f64::sinh, cosh = f64::cosh, atanh = f64::atanh;
+                 atan2 = f64::atan2, hypot = f64::hypot, copysign = f64::copysign);
+
+#[cfg(all(feature = "libm", not(feature = "std")))]
+impl_Float!(f32; abs = ::libm::fabsf, sqrt = ::libm::sqrtf, exp = ::libm::expf, ln = ::libm::logf, sin = ::libm::sinf, cos = ::libm::cosf,
+                 sinh = ::libm::sinhf, cosh = ::libm::coshf, atanh = ::libm::atanhf;
+                 atan2 = ::libm::atan2f, hypot = ::libm::hypotf, copysign = ::libm::copysignf);
+
+#[cfg(all(feature = "libm", not(feature = "std")))]
+impl_Float!(f64; abs = ::libm::fabs, sqrt = ::libm::sqrt, exp = ::libm::exp, ln = ::libm::log, sin = ::libm::sin, cos = ::libm::cos,
+                 sinh = ::libm::sinh, cosh = ::libm::cosh, atanh = ::libm::atanh;
+                 atan2 = ::libm::atan2, hypot = ::libm::hypot, copysign = ::libm::copysign);
@@ -0,0 +1,151 @@
+use core::array;
+use core::iter;
+use core::ops::{ Index, IndexMut };
+use idem::{ Zero, One };
+
+use { Complex, SelfConjugate, Sign, from_rect };
+
+/// An element of some Cayley-Dickson algebra, seen as a flat vector of `DIM` base scalars
+///
+/// Components are in canonical basis order, i.e. `1, i, j, k` for quaternions and `e₀ … e₇` for octonions, which is also the order of the leaves of the `from_rect` nesting.
+///
+/// `SelfConjugate` is transparent here: a dual quaternion has 8 components.
+pub trait Hypercomplex: Sized {
+    /// The base scalar type
+    type Scalar;
+
+    /// Number of base scalar components: 1 for scalars, then 2, 4, 8, 16, …
+    const DIM: usize;
+
+    /// # Panics
+    ///
+    /// Panics if `i >= DIM`.
+    fn component(&self, i: usize) -> &Self::Scalar;
+
+    /// # Panics
+    ///
+    /// Panics if `i >= DIM`.
+    fn component_mut(&mut self, i: usize) -> &mut Self::Scalar;
+
+    fn components(&self) -> impl Iterator<Item = &Self::Scalar>;
+
+    fn components_mut(&mut self) -> impl Iterator<Item = &mut Self::Scalar>;
+
+    /// Take the next `DIM` components of `it`, or `None` if it runs out first.
+    fn from_components<I: Iterator<Item = Self::Scalar>>(it: &mut I) -> Option<Self>;
+
+    /// The square of the unit added by the `level`th construction from the base scalar, i.e. of `e_{2^level}`: -1, 1 or 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2^level >= DIM`.
+    #[inline]
+    fn unit_square(level: usize) -> i8 { panic!("no unit at level {}", level) }
+
+    /// The `n`th unit of the canonical basis
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n >= DIM`.
+    #[inline]
+    fn basis(n: usize) -> Self where Self::Scalar: Zero + One {
+        assert!(n < Self::DIM, "basis index out of range");
+        Self::from_components(&mut (0..Self::DIM).map(|i| if i == n { Self::Scalar::one } else { Self::Scalar::zero }))
+            .expect("`from_components` takes more than `DIM`")
+    }
+
+    /// `N` must be `DIM`, else this fails to compile.
+    #[inline]
+    fn to_array<const N: usize>(&self) -> [Self::Scalar; N] where Self::Scalar: Clone {
+        const { assert!(N == Self::DIM, "array length is not `Hypercomplex::DIM`") };
+        let mut it = self.components();
+        array::from_fn(|_| it.next().expect("`components` shorter than `DIM`").clone())
+    }
+
+    /// `N` must be `DIM`, else this fails to compile.
+    #[inline]
+    fn from_array<const N: usize>(a: [Self::Scalar; N]) -> Self {
+        const { assert!(N == Self::DIM, "array length is not `Hypercomplex::DIM`") };
+        Self::from_components(&mut IntoIterator::into_iter(a)).expect("`from_components` takes more than `DIM`")
+    }
+}
+
+impl<S: Sign<A>, A: Hypercomplex> Hypercomplex for Complex<A, S> {
+    type Scalar = A::Scalar;
+    const DIM: usize = 2 * A::DIM;
+
+    #[inline] fn component(&self, i: usize) -> &A::Scalar {
+        let Complex(_, a, b) = self;
+        if i < A::DIM { a.component(i) } else { b.component(i - A::DIM) }
+    }
+
+    #[inline] fn component_mut(&mut self, i: usize) -> &mut A::Scalar {
+        let Complex(_, a, b) = self;
+        if i < A::DIM { a.component_mut(i) } else { b.component_mut(i - A::DIM) }
+    }
+
+    #[inline] fn components(&self) -> impl Iterator<Item = &A::Scalar> {
+        let Complex(_, a, b) = self;
+        a.components().chain(b.components())
+    }
+
+    #[inline] fn components_mut(&mut self) -> impl Iterator<Item = &mut A::Scalar> {
+        let Complex(_, a, b) = self;
+        a.components_mut().chain(b.components_mut())
+    }
+
+    #[inline] fn from_components<I: Iterator<Item = A::Scalar>>(it: &mut I) -> Option<Self> {
+        let a = A::from_components(it)?;
+        let b = A::from_components(it)?;
+        Some(from_rect(a, b))
+    }
+
+    #[inline] fn unit_square(level: usize) -> i8 {
+        if level == A::DIM.trailing_zeros() as usize { S::I8 } else { A::unit_square(level) }
+    }
+}
+
+impl<A: Hypercomplex> Hypercomplex for SelfConjugate<A> {
+    type Scalar = A::Scalar;
+    const DIM: usize = A::DIM;
+
+    #[inline] fn component(&self, i: usize) -> &A::Scalar { self.0.component(i) }
+    #[inline] fn component_mut(&mut self, i: usize) -> &mut A::Scalar { self.0.component_mut(i) }
+    #[inline] fn components(&self) -> impl Iterator<Item = &A::Scalar> { self.0.components() }
+    #[inline] fn components_mut(&mut self) -> impl Iterator<Item = &mut A::Scalar> { self.0.components_mut() }
+    #[inline] fn from_components<I: Iterator<Item = A::Scalar>>(it: &mut I) -> Option<Self> {
+        A::from_components(it).map(SelfConjugate)
+    }
+    #[inline] fn unit_square(level: usize) -> i8 { A::unit_square(level) }
+}
+
+macro_rules! impl_Hypercomplex_scalar {
+    ($($t: ty),*) => ($(impl Hypercomplex for $t {
+        type Scalar = $t;
+        const DIM: usize = 1;
+
+        #[inline] fn component(&self, i: usize) -> &$t {
+            assert_eq!(0, i, "component index out of range");
+            self
+        }
+
+        #[inline] fn component_mut(&mut self, i: usize) -> &mut $t {
+            assert_eq!(0, i, "component index out of range");
+            self
+        }
+
+        #[inline] fn components(&self) -> impl Iterator<Item = &$t> { iter::once(self) }
+        #[inline] fn components_mut(&mut self) -> impl Iterator<Item = &mut $t> { iter::once(self) }
+        #[inline] fn from_components<I: Iterator<Item = $t>>(it: &mut I) -> Option<$t> { it.next() }
+    })*);
+}
+impl_Hypercomplex_scalar!(f32, f64, isize, i8, i16, i32, i64);
+
+impl<S: Sign<A>, A: Hypercomplex> Index<usize> for Complex<A, S> {
+    type Output = A::Scalar;
+    #[inline] fn index(&self, i: usize) -> &A::Scalar { self.component(i) }
+}
+
+impl<S: Sign<A>, A: Hypercomplex> IndexMut<usize> for Complex<A, S> {
+    #[inline] fn index_mut(&mut self, i: usize) -> &mut A::Scalar { self.component_mut(i) }
+}
@@ -0,0 +1,349 @@
+//! Invertibility and checked division
+//!
+//! An element `x` of `Complex<A, S>` is invertible if its norm `x x*` is invertible in the base, whereupon `x⁻¹ = x* / (x x*)`.
+//! In the split and dual algebras, and over non-field scalars such as dual numbers or integers, many non-zero elements are not.
+//!
+//! From the sedenions up, invertible elements can still be zero divisors: e.g. `(e1 + e10)(e4 - e15) = 0`, though both factors have inverses, as the algebra is not associative.
+//!
+//! Over the primitive scalars, told apart by `TypeId`, this is decided without overflow: over integers exactly, by arithmetic modulo enough primes, and over `f32` and `f64` after scaling `x` by its largest component magnitude.
+//! Over any other scalar, the arithmetic is taken to be exact, as for big integers and rationals.
+
+use core::any::Any;
+use core::{ array, fmt };
+use core::convert::TryFrom;
+use core::ops::*;
+use idem::{ One, Zero };
+
+use { CayleyTable, Complex, Conjugable, Hypercomplex, Norm, SelfConjugate, Sign, SignedUnit };
+
+/// Error dividing by an element with no inverse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotInvertible;
+
+impl fmt::Display for NotInvertible {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result { fmt.write_str("divisor not invertible") }
+}
+
+/// Whether an element has a two-sided inverse
+///
+/// For the base scalars, this is whether they are non-zero, or for integers whether they are ±1.
+/// For `Complex`, it is whether the norm `x x*` is invertible in `Norm::Real`, so over integers whether it is a unit there, e.g. ±1 if that is the base scalar.
+pub trait Invertible {
+    fn is_invertible(&self) -> bool;
+}
+
+impl<S: Sign<A>, A, F> Invertible for Complex<A, S>
+  where F: 'static + Clone + PartialEq + Zero + One + Neg<Output = F>,
+        Self: Clone + Hypercomplex<Scalar = F> + Conjugable + Mul<Output = Self> + Norm, <Self as Norm>::Real: Hypercomplex + Invertible {
+    fn is_invertible(&self) -> bool {
+        match Exact::new(self) {
+            Some(x) => x.is_unit_norm(<Self as Norm>::Real::DIM),
+            None => scale(self.clone()).norm_sqr().is_invertible(),
+        }
+    }
+}
+
+impl<A: Invertible> Invertible for SelfConjugate<A> {
+    #[inline] fn is_invertible(&self) -> bool { self.0.is_invertible() }
+}
+
+macro_rules! impl_Invertible_float {
+    ($($t: ty),*) => ($(impl Invertible for $t {
+        #[inline] fn is_invertible(&self) -> bool { *self != 0. }
+    })*);
+}
+impl_Invertible_float!(f32, f64);
+
+macro_rules! impl_Invertible_int {
+    ($($t: ty),*) => ($(impl Invertible for $t {
+        #[inline] fn is_invertible(&self) -> bool { *self == 1 || *self == -1 }
+    })*);
+}
+impl_Invertible_int!(isize, i8, i16, i32, i64);
+
+impl<S: Sign<A>, A, F> Complex<A, S>
+  where F: 'static + Clone + PartialEq + Zero + One + Neg<Output = F>,
+        Self: Clone + Hypercomplex<Scalar = F> + Conjugable + Mul<Output = Self> + Norm, <Self as Norm>::Real: Hypercomplex + Invertible {
+    /// `x⁻¹`, if `x` is invertible, and over integers, if it is representable
+    pub fn checked_inv(self) -> Option<Self> where Self: One + Div<Output = Self> {
+        match Exact::new(&self) {
+            Some(x) => {
+                let y = x.inverse(<Self as Norm>::Real::DIM)?;
+                let mut z = self;
+                for (c, &y) in z.components_mut().zip(&y) { if !set_int(c, y) { return None } }
+                Some(z)
+            },
+            None => if self.is_invertible() { Some(Self::one / self) } else { None },
+        }
+    }
+
+    /// `x / y`, if `y` is invertible, and over integers, if `y⁻¹` is representable, as then `x / y = x y⁻¹`
+    pub fn checked_div(self, other: Self) -> Option<Self> where Self: One + Div<Output = Self> {
+        if int(&F::zero).is_some() { other.checked_inv().map(|y| self * y) }
+        else if other.is_invertible() { Some(self / other) } else { None }
+    }
+
+    /// `x / y`, or `NotInvertible` if `checked_div` gives `None`
+    #[inline]
+    pub fn try_div(self, other: Self) -> Result<Self, NotInvertible> where Self: One + Div<Output = Self> {
+        self.checked_div(other).ok_or(NotInvertible)
+    }
+
+    /// Whether `x` is non-zero and `x y = 0` or `y x = 0` for some non-zero `y`
+    ///
+    /// This asks whether left or right multiplication by `x` is singular, which up to the octonions is whether `x x*` is zero or a zero divisor.
+    /// Over integers, it is exact, by elimination modulo enough primes that no determinant can vanish modulo all of them unless it is 0.
+    /// Over `f32` and `f64`, up to 8 dimensions, it is whether `x` is not invertible; from the sedenions up, it is by fraction-free elimination after scaling `x` by its largest component magnitude, which is exact only while the minors are exactly representable, e.g. for components in a small integer ratio.
+    /// Over other scalars, it is by fraction-free elimination, which must be exact.
+    /// It supports up to 32 dimensions.
+    pub fn is_zero_divisor(&self) -> bool where F: Sub<Output = F> + Mul<Output = F> + Div<Output = F> {
+        const { assert!(Self::DIM <= MAX_DIM, "too many dimensions") };
+        if self.components().all(|c| *c == F::zero) { return false }
+        if let Some(x) = Exact::new(self) { return x.is_zero_divisor() }
+        if Self::DIM <= 8 && float(&F::zero).is_some() { return !self.is_invertible() }
+        let x = scale(self.clone());
+        let mut left: [[F; MAX_DIM]; MAX_DIM] = array::from_fn(|_| array::from_fn(|_| F::zero));
+        let mut right = left.clone();
+        for j in 0..Self::DIM {
+            for (m, y) in [(&mut left, x.clone() * Self::basis(j)), (&mut right, Self::basis(j) * x.clone())] {
+                for (e, c) in m[j].iter_mut().zip(y.components()) { *e = c.clone(); }
+            }
+        }
+        bareiss(&mut left, Self::DIM) || bareiss(&mut right, Self::DIM)
+    }
+}
+
+/// The most dimensions `is_zero_divisor` supports
+const MAX_DIM: usize = 32;
+
+/// `c` as `f64`, if it is `f32` or `f64`
+fn float<F: 'static>(c: &F) -> Option<f64> {
+    let c = c as &dyn Any;
+    c.downcast_ref::<f64>().cloned().or_else(|| c.downcast_ref::<f32>().map(|&c| c as f64))
+}
+
+/// `x` divided by its largest component magnitude, if it is over `f32` or `f64` and that is finite and non-zero
+fn scale<T: Hypercomplex>(mut x: T) -> T where T::Scalar: 'static {
+    let m = x.components().filter_map(float).fold(0., |m: f64, c| m.max(c.abs()));
+    if m.is_finite() && m > 0. {
+        for c in x.components_mut() {
+            let c = c as &mut dyn Any;
+            if let Some(c) = c.downcast_mut::<f64>() { *c /= m }
+            if let Some(c) = c.downcast_mut::<f32>() { *c /= m as f32 }
+        }
+    }
+    x
+}
+
+macro_rules! def_int {
+    ($($t: ident),*) => (
+        /// `c` as `i128`, if it is of a primitive integer type
+        fn int<F: 'static>(c: &F) -> Option<i128> {
+            let c = c as &dyn Any;
+            $(if let Some(&c) = c.downcast_ref::<$t>() { return Some(c as i128) })*
+            None
+        }
+
+        /// Set `c` to `v`, if it is of a primitive integer type that can represent `v`.
+        fn set_int<F: 'static>(c: &mut F, v: i128) -> bool {
+            let c = c as &mut dyn Any;
+            $(if let Some(c) = c.downcast_mut::<$t>() { return $t::try_from(v).map(|v| *c = v).is_ok() })*
+            false
+        }
+    );
+}
+def_int!(isize, i8, i16, i32, i64);
+
+/// An element over a primitive integer type, with the multiplication table of its algebra
+struct Exact { dim: usize, c: [i128; MAX_DIM], conj: [i8; MAX_DIM], mul: [[SignedUnit; MAX_DIM]; MAX_DIM] }
+
+impl Exact {
+    fn new<T, F>(x: &T) -> Option<Self>
+      where T: Hypercomplex<Scalar = F> + Conjugable + Mul<Output = T>, F: 'static + PartialEq + Zero + One + Neg<Output = F> {
+        let dim = T::DIM;
+        let mut c = [0; MAX_DIM];
+        for (c, x) in c.iter_mut().zip(x.components()) { *c = int(x)?; }
+        let table = CayleyTable::<T>::new();
+        let none = SignedUnit { sign: 0, index: 0 };
+        Some(Exact {
+            dim, c,
+            conj: array::from_fn(|n| if n >= dim || *T::basis(n).conjugate().component(n) == F::one { 1 } else { -1 }),
+            mul: array::from_fn(|m| array::from_fn(|n| if m < dim && n < dim { table.get(m, n) } else { none })),
+        })
+    }
+
+    /// Bits enough to hold the largest component magnitude
+    fn bits(&self) -> u32 { bits(self.c.iter().fold(0, |b, c| b.max(c.unsigned_abs()))) }
+
+    /// `x` and `x*` modulo `p`
+    fn residues(&self, p: u64) -> ([u64; MAX_DIM], [u64; MAX_DIM]) {
+        let x = array::from_fn(|n| self.c[n].rem_euclid(p as i128) as u64);
+        (x, array::from_fn(|n| signed(x[n], self.conj[n], p)))
+    }
+
+    /// `u v` modulo `p`
+    fn mul_mod(&self, u: &[u64; MAX_DIM], v: &[u64; MAX_DIM], p: u64) -> [u64; MAX_DIM] {
+        let mut w = [0; MAX_DIM];
+        for (row, &u) in self.mul[..self.dim].iter().zip(u) { for (&e, &v) in row[..self.dim].iter().zip(v) {
+            w[e.index] = (w[e.index] + signed(u * v % p, e.sign, p)) % p;
+        } }
+        w
+    }
+
+    /// Entry `(k, n)` of the matrix of left multiplication by `u` on the first `r` components modulo `p`
+    fn left_mod(&self, u: &[u64; MAX_DIM], p: u64, k: usize, n: usize, r: usize) -> u64 {
+        (0..r).filter(|&m| self.mul[m][n].index == k).fold(0, |a, m| (a + signed(u[m], self.mul[m][n].sign, p)) % p)
+    }
+
+    fn is_zero_divisor(&self) -> bool {
+        let (mut left, mut right) = ([[0; MAX_DIM]; MAX_DIM], [[0; MAX_DIM]; MAX_DIM]);
+        for m in 0..self.dim { for n in 0..self.dim {
+            let (e, f) = (self.mul[m][n], self.mul[n][m]);
+            left[e.index][n] += self.c[m] * e.sign as i128;
+            right[f.index][n] += self.c[m] * f.sign as i128;
+        } }
+        singular(&left, self.dim) || singular(&right, self.dim)
+    }
+
+    /// Whether `x x*`, which lies in the subalgebra of the first `r` components, is a unit there, i.e. whether multiplication by it there has determinant ±1
+    fn is_unit_norm(&self, r: usize) -> bool {
+        // `|(x x*)_k| ≤ dim² b²`, and by Hadamard's bound `|det| ≤ (√r e)ʳ` for entries at most `e` in magnitude.
+        let e = 2 * self.bits() + 2 * log2_ceil(self.dim) + log2_ceil(r);
+        let det_bits = r as u32 * (e + log2_ceil(r).div_ceil(2)) + 1;
+        let (mut one, mut minus_one) = (true, true);
+        for p in primes(det_bits) {
+            let (x, y) = self.residues(p);
+            let n = self.mul_mod(&x, &y, p);
+            let d = det_mod(r, p, |k, j| self.left_mod(&n, p, k, j, r));
+            one &= d == 1;
+            minus_one &= d == p - 1;
+            if !one && !minus_one { return false }
+        }
+        true
+    }
+
+    /// `x⁻¹ = x* (x x*)⁻¹`, if `x x*` is a unit and `x⁻¹` has components of at most 64 bits
+    fn inverse(&self, r: usize) -> Option<[i128; MAX_DIM]> {
+        if !self.is_unit_norm(r) { return None }
+        // Reconstruct from its residues modulo primes whose product exceeds 2⁶⁶, then check `x y = y x = 1` modulo enough primes that it must hold.
+        let (mut y, mut m) = ([0i128; MAX_DIM], 1i128);
+        for p in primes(66) {
+            let (x, xs) = self.residues(p);
+            let n = self.mul_mod(&x, &xs, p);
+            let mut z = [0; MAX_DIM];
+            z[0] = 1;
+            solve_mod(r, p, |k, j| self.left_mod(&n, p, k, j, r), &mut z)?;
+            let w = self.mul_mod(&xs, &z, p);
+            let mi = pow_mod((m % p as i128) as u64, p - 2, p) as i128;
+            for (y, &w) in y.iter_mut().zip(&w) { *y += m * ((w as i128 - *y).rem_euclid(p as i128) * mi % p as i128); }
+            m *= p as i128;
+        }
+        for y in y.iter_mut() { if *y > m / 2 { *y -= m } }
+        if y.iter().any(|y| y.unsigned_abs() >= 1 << 64) { return None }
+        let bits = self.bits() + 64 + log2_ceil(self.dim) + 1;
+        for p in primes(bits) {
+            let (x, _) = self.residues(p);
+            let v = array::from_fn(|n| y[n].rem_euclid(p as i128) as u64);
+            let one = |w: [u64; MAX_DIM]| w[0] == 1 && w[1..].iter().all(|&w| w == 0);
+            if !one(self.mul_mod(&x, &v, p)) || !one(self.mul_mod(&v, &x, p)) { return None }
+        }
+        Some(y)
+    }
+}
+
+fn bits(n: u128) -> u32 { 128 - n.leading_zeros() }
+
+fn log2_ceil(n: usize) -> u32 { usize::BITS - n.saturating_sub(1).leading_zeros() }
+
+/// `s v` modulo `p`, for `s` of -1, 0 or 1
+fn signed(v: u64, s: i8, p: u64) -> u64 { if s == 0 { 0 } else if s > 0 { v } else { (p - v) % p } }
+
+/// Primes above 2³⁰ whose product exceeds `2^bits`
+fn primes(bits: u32) -> impl Iterator<Item = u64> {
+    (1 << 30..1 << 31).rev().filter(|&p| is_prime(p)).take(bits as usize / 30 + 1)
+}
+
+/// Whether the leading `n` by `n` block of `m` is singular, modulo primes whose product exceeds Hadamard's bound on its determinant
+fn singular(m: &[[i128; MAX_DIM]; MAX_DIM], n: usize) -> bool {
+    let b = m[..n].iter().flat_map(|r| &r[..n]).fold(0, |b, e| b.max(e.unsigned_abs()));
+    // `|det m| ≤ (√n b)ⁿ`
+    let det_bits = n as u32 * (bits(b) + log2_ceil(n).div_ceil(2)) + 1;
+    primes(det_bits).all(|p| 0 == det_mod(n, p, |i, j| m[i][j].rem_euclid(p as i128) as u64))
+}
+
+/// Gaussian elimination modulo `p` of the `n` by `n` matrix with entries `a(i, j)`, on `b` as well if given, giving the determinant
+fn eliminate(n: usize, p: u64, a: impl Fn(usize, usize) -> u64, mut b: Option<&mut [u64; MAX_DIM]>) -> u64 {
+    let mut m = [[0; MAX_DIM]; MAX_DIM];
+    for (i, r) in m[..n].iter_mut().enumerate() { for (j, e) in r[..n].iter_mut().enumerate() { *e = a(i, j) } }
+    let mut det = 1;
+    for k in 0..n {
+        let r = match (k..n).find(|&i| m[i][k] != 0) { Some(r) => r, None => return 0 };
+        if r != k {
+            m.swap(k, r);
+            if let Some(ref mut b) = b { b.swap(k, r) }
+            det = p - det;
+        }
+        det = det * m[k][k] % p;
+        let inv = pow_mod(m[k][k], p - 2, p);
+        for e in m[k][k..n].iter_mut() { *e = *e * inv % p }
+        if let Some(ref mut b) = b { b[k] = b[k] * inv % p }
+        for i in (0..n).filter(|&i| i != k) {
+            let f = m[i][k];
+            let (top, rest) = m.split_at_mut(i.max(k));
+            let (row, pivot) = if i < k { (&mut top[i], &rest[0]) } else { (&mut rest[0], &top[k]) };
+            for (e, &d) in row[k..n].iter_mut().zip(&pivot[k..n]) { *e = (*e + (p - f) * d) % p }
+            if let Some(ref mut b) = b { b[i] = (b[i] + (p - f) * b[k]) % p }
+        }
+    }
+    det
+}
+
+fn det_mod(n: usize, p: u64, a: impl Fn(usize, usize) -> u64) -> u64 { eliminate(n, p, a, None) }
+
+/// Solve `a z = b` modulo `p` in place, if `a` is invertible modulo `p`.
+fn solve_mod(n: usize, p: u64, a: impl Fn(usize, usize) -> u64, b: &mut [u64; MAX_DIM]) -> Option<()> {
+    if 0 == eliminate(n, p, a, Some(b)) { None } else { Some(()) }
+}
+
+fn pow_mod(mut a: u64, mut e: u64, p: u64) -> u64 {
+    let mut r = 1;
+    while e > 0 {
+        if 1 == e & 1 { r = r * a % p }
+        a = a * a % p;
+        e >>= 1;
+    }
+    r
+}
+
+/// Miller-Rabin, deterministic for `61 < n < 2³²`
+fn is_prime(n: u64) -> bool {
+    if n.is_multiple_of(2) { return n == 2 }
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) { d /= 2; s += 1 }
+    [2, 7, 61].iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 { return true }
+        for _ in 1..s {
+            x = x * x % n;
+            if x == n - 1 { return true }
+        }
+        false
+    })
+}
+
+/// Whether the leading `n` by `n` block of `m` is singular, by Bareiss's algorithm
+fn bareiss<F>(m: &mut [[F; MAX_DIM]; MAX_DIM], n: usize) -> bool where F: Clone + PartialEq + Zero + One + Sub<Output = F> + Mul<Output = F> + Div<Output = F> {
+    let mut prev = F::one;
+    for k in 0..n {
+        let p = match (k..n).find(|&i| m[i][k] != F::zero) { Some(p) => p, None => return true };
+        m.swap(k, p);
+        let pivot = m[k][k].clone();
+        for i in k+1..n {
+            for j in k+1..n {
+                m[i][j] = (pivot.clone() * m[i][j].clone() - m[i][k].clone() * m[k][j].clone()) / prev.clone();
+            }
+        }
+        prev = pivot;
+    }
+    false
+}
@@ -0,0 +1,178 @@
+//! Interleaved I/Q sample files, as written by software-defined radio tools such as `rtl_sdr` and `hackrf_transfer`
+//!
+//! Samples are scaled to about `[-1, 1)` in `Complex<f32>`, and to the full range of `Complex<i16>`, i.e. 32768 times that.
+//! Unsigned 8-bit samples are offset by 127.5, so that they are symmetric about zero, as `rtl_sdr` is taken to have no DC bias.
+
+use std::io::{ self, Read, Write };
+use std::vec::Vec;
+
+use { Complex, from_rect };
+
+/// Sample format of an interleaved I/Q stream, in-phase component first; the multi-byte formats are little-endian
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IqFormat {
+    /// Unsigned 8-bit, offset by 127.5, as from `rtl_sdr`
+    Cu8,
+    /// Signed 8-bit, as from `hackrf_transfer`
+    Cs8,
+    /// Signed 16-bit
+    Cs16,
+    /// 32-bit float, as from GNU Radio
+    Cf32,
+}
+
+impl IqFormat {
+    /// Format named by a file extension, i.e. `cu8`, `cs8`, `cs16` or `cf32`
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "cu8" => Some(IqFormat::Cu8),
+            "cs8" => Some(IqFormat::Cs8),
+            "cs16" => Some(IqFormat::Cs16),
+            "cf32" => Some(IqFormat::Cf32),
+            _ => None,
+        }
+    }
+
+    /// Bytes per complex sample
+    #[inline]
+    pub fn sample_size(self) -> usize { 2 * self.component_size() }
+
+    #[inline]
+    fn component_size(self) -> usize {
+        match self { IqFormat::Cu8 | IqFormat::Cs8 => 1, IqFormat::Cs16 => 2, IqFormat::Cf32 => 4 }
+    }
+
+    fn decode(self, b: &[u8]) -> f32 {
+        match self {
+            IqFormat::Cu8 => (b[0] as f32 - 127.5) / 128.,
+            IqFormat::Cs8 => b[0] as i8 as f32 / 128.,
+            IqFormat::Cs16 => i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.,
+            IqFormat::Cf32 => f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        }
+    }
+
+    /// Nearest, saturating
+    fn encode(self, x: f32, out: &mut Vec<u8>) {
+        match self {
+            IqFormat::Cu8 => out.push((x * 128. + 127.5).round() as u8),
+            IqFormat::Cs8 => out.push((x * 128.).round() as i8 as u8),
+            IqFormat::Cs16 => out.extend_from_slice(&((x * 32768.).round() as i16).to_le_bytes()),
+            IqFormat::Cf32 => out.extend_from_slice(&x.to_le_bytes()),
+        }
+    }
+}
+
+/// Reads samples from an I/Q stream
+#[derive(Debug)]
+pub struct IqReader<R> {
+    inner: R,
+    format: IqFormat,
+    buf: Vec<u8>,
+    /// Bytes of a sample cut off by the end of the stream, read after the samples before it
+    rest: Vec<u8>,
+}
+
+impl<R: Read> IqReader<R> {
+    #[inline]
+    pub fn new(inner: R, format: IqFormat) -> Self { IqReader { inner, format, buf: Vec::new(), rest: Vec::new() } }
+
+    #[inline]
+    pub fn format(&self) -> IqFormat { self.format }
+
+    #[inline]
+    pub fn into_inner(self) -> R { self.inner }
+
+    /// Fill `out` with the next samples, or as many as are left; return how many were read, so 0 at the end of the stream
+    ///
+    /// If the stream ends within a sample, the samples before it are returned first, and only then is it an error.
+    pub fn read(&mut self, out: &mut [Complex<f32>]) -> io::Result<usize> {
+        let format = self.format;
+        let n = self.fill(out.len())?;
+        for (b, x) in self.buf[..n * format.sample_size()].chunks_exact(format.sample_size()).zip(&mut *out) {
+            let (i, q) = b.split_at(format.component_size());
+            *x = from_rect(format.decode(i), format.decode(q));
+        }
+        Ok(n)
+    }
+
+    /// As `read`, but scaled to `Complex<i16>`: `cs16` samples are as in the stream, and `cf32` samples are rounded to nearest, saturating.
+    pub fn read_i16(&mut self, out: &mut [Complex<i16>]) -> io::Result<usize> {
+        let format = self.format;
+        let n = self.fill(out.len())?;
+        for (b, x) in self.buf[..n * format.sample_size()].chunks_exact(format.sample_size()).zip(&mut *out) {
+            let (i, q) = b.split_at(format.component_size());
+            *x = from_rect(to_i16(format.decode(i)), to_i16(format.decode(q)));
+        }
+        Ok(n)
+    }
+
+    /// Read up to `n` samples into `buf`, returning how many
+    fn fill(&mut self, n: usize) -> io::Result<usize> {
+        let size = self.format.sample_size();
+        if 0 == n { return Ok(0) }
+        self.buf.clear();
+        self.buf.append(&mut self.rest);
+        let mut k = self.buf.len();
+        self.buf.resize(n * size, 0);
+        while k < self.buf.len() {
+            match self.inner.read(&mut self.buf[k..]) {
+                Ok(0) => break,
+                Ok(m) => k += m,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => return Err(e),
+            }
+        }
+        if !k.is_multiple_of(size) {
+            if k < size { return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stream ends within an I/Q sample")) }
+            self.rest.extend_from_slice(&self.buf[k - k % size..k]);
+        }
+        Ok(k / size)
+    }
+}
+
+#[inline]
+fn to_i16(x: f32) -> i16 { (x * 32768.).round() as i16 }
+
+/// Writes samples to an I/Q stream
+#[derive(Debug)]
+pub struct IqWriter<W> {
+    inner: W,
+    format: IqFormat,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> IqWriter<W> {
+    #[inline]
+    pub fn new(inner: W, format: IqFormat) -> Self { IqWriter { inner, format, buf: Vec::new() } }
+
+    #[inline]
+    pub fn format(&self) -> IqFormat { self.format }
+
+    #[inline]
+    pub fn into_inner(self) -> W { self.inner }
+
+    /// Write all of `xs`, rounding to nearest and saturating for the integer formats
+    pub fn write(&mut self, xs: &[Complex<f32>]) -> io::Result<()> {
+        self.buf.clear();
+        for x in xs {
+            let (&i, &q) = x.as_rect();
+            self.format.encode(i, &mut self.buf);
+            self.format.encode(q, &mut self.buf);
+        }
+        self.inner.write_all(&self.buf)
+    }
+
+    /// As `write`, but from `Complex<i16>` scaled as by `IqReader::read_i16`
+    pub fn write_i16(&mut self, xs: &[Complex<i16>]) -> io::Result<()> {
+        self.buf.clear();
+        for x in xs {
+            let (&i, &q) = x.as_rect();
+            self.format.encode(i as f32 / 32768., &mut self.buf);
+            self.format.encode(q as f32 / 32768., &mut self.buf);
+        }
+        self.inner.write_all(&self.buf)
+    }
+
+    #[inline]
+    pub fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
+}
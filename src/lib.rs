@@ -1,36 +1,97 @@
 #![no_std]
 
-#![feature(const_fn)]
-#![feature(const_fn_union)]
-#![feature(const_let)]
-#![feature(untagged_unions)]
-
+#[cfg(any(test, feature = "std"))] extern crate std;
+#[cfg(feature = "libm")] extern crate libm;
+#[cfg(feature = "serde")] extern crate serde;
+#[cfg(feature = "bytemuck")] extern crate bytemuck;
+#[cfg(feature = "num-bigint")] extern crate num_bigint;
+#[cfg(feature = "num-rational")] extern crate num_rational;
+#[cfg(all(test, feature = "serde"))] extern crate serde_json;
+#[cfg(all(test, feature = "serde"))] extern crate bincode;
 extern crate idem;
 extern crate typenum;
 
 use core::cmp::*;
 use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 use core::ops::*;
+use core::ptr::{ self, addr_of };
 use idem::*;
 use typenum::consts::{ P1, N1 };
 use typenum::int::{ Integer, Z0 };
 
-pub trait Sign<A> : Integer { fn sign(A) -> A; }
+#[macro_use] mod macros;
+mod alias;
+mod annex_g;
+mod bytes;
+mod display;
+mod div;
+mod elementary;
+mod embed;
+mod flat;
+mod float;
+mod hypercomplex;
+mod invert;
+#[cfg(feature = "std")] mod iq;
+mod map;
+mod norm;
+#[cfg(feature = "std")] mod npy;
+#[cfg(any(feature = "num-bigint", feature = "num-rational"))] mod num;
+mod parse;
+mod polar;
+mod scalar;
+#[cfg(feature = "serde")] mod serialize;
+mod table;
+pub use alias::*;
+pub use annex_g::*;
+pub use bytes::*;
+pub use display::*;
+pub use elementary::*;
+pub use embed::*;
+pub use flat::*;
+pub use float::*;
+pub use hypercomplex::*;
+pub use invert::*;
+#[cfg(feature = "std")] pub use iq::*;
+#[doc(hidden)] pub use macros::{ __zero, __one, __minus_one };
+pub use map::*;
+pub use norm::*;
+#[cfg(feature = "std")] pub use npy::*;
+pub use parse::*;
+pub use table::*;
+
+pub trait Sign<A> : Integer { fn sign(a: A) -> A; }
 impl<A>                  Sign<A> for P1 { fn sign(a: A) -> A { a } }
 impl<A: Neg<Output = A>> Sign<A> for N1 { fn sign(a: A) -> A { a.neg() } }
 impl<A: Zero>            Sign<A> for Z0 { fn sign(_: A) -> A { A::zero } }
 
 /// Cayley-Dickson construction
-#[derive(Debug)]
+///
+/// The layout is that of `[A; 2]`, so any nesting over a scalar is laid out as the array of its components; see `Flat`.
+#[repr(C)]
 pub struct Complex<A, S: Sign<A> = N1>(PhantomData<S>, A, A);
 
 impl<S: Sign<A>, A> Complex<A, S> {
     #[inline] pub const fn from_rect(re: A, im: A) -> Self { Complex(PhantomData, re, im) }
     #[inline] pub const fn into_rect(self) -> (A, A) {
-        #[allow(unions_with_drop_fields)]
-        union U<A, S: Sign<A>> { c: Complex<A, S> }
-        let u = U { c: self };
-        unsafe { (u.c.1, u.c.2) }
+        // A `const fn` may not move fields out of `self`, as that would run its destructor.
+        let c = ManuallyDrop::new(self);
+        let p = &c as *const ManuallyDrop<Self> as *const Self;
+        unsafe { (ptr::read(addr_of!((*p).1)), ptr::read(addr_of!((*p).2))) }
+    }
+
+    #[inline] pub const fn as_rect(&self) -> (&A, &A) { (&self.1, &self.2) }
+    #[inline] pub const fn as_rect_mut(&mut self) -> (&mut A, &mut A) { (&mut self.1, &mut self.2) }
+
+    #[inline] pub const fn re(&self) -> &A { &self.1 }
+    #[inline] pub const fn im(&self) -> &A { &self.2 }
+    #[inline] pub const fn re_mut(&mut self) -> &mut A { &mut self.1 }
+    #[inline] pub const fn im_mut(&mut self) -> &mut A { &mut self.2 }
+
+    /// Convert every base scalar to `B`, keeping the signs of the construction, e.g. `Quaternion<f32>` to `Quaternion<f64>`
+    #[inline]
+    pub fn cast<B>(self) -> <Self as MapScalar<B>>::Output where Self: MapScalar<B>, <Self as Hypercomplex>::Scalar: Into<B> {
+        self.map(Into::into)
     }
 
     #[allow(clippy::wrong_self_convention)]
@@ -56,16 +117,16 @@ impl<S: Sign<A>, A: Zero + One> One for Complex<A, S> {
 }
 
 impl<S: Sign<A>, A: PartialEq> PartialEq for Complex<A, S> {
-    #[inline] fn eq(&self, &Complex(_, ref c, ref d): &Self) -> bool {
-        let &Complex(_, ref a, ref b) = self;
+    #[inline] fn eq(&self, Complex(_, c, d): &Self) -> bool {
+        let Complex(_, a, b) = self;
         (a, b) == (c, d)
     }
 }
 
 impl<S: Sign<A>, A: Eq> Eq for Complex<A, S> {}
 
-impl<S: Sign<A>, A: Copy> From<A> for Complex<A, S> where Z0: Sign<A> {
-    fn from(x: A) -> Self { from_rect(x, Z0::sign(x)) }
+impl<S: Sign<A>, A: Zero> From<A> for Complex<A, S> {
+    #[inline] fn from(x: A) -> Self { from_rect(x, A::zero) }
 }
 
 pub trait Conjugable {
@@ -109,28 +170,87 @@ impl<S: Sign<A>, A: Neg<Output = A>> Neg for Complex<A, S> {
     }
 }
 
-impl<S: Sign<A>, A: Copy + Add<Output = A> + Conjugable + Mul<Output = A>> Mul for Complex<A, S> {
+impl<S: Sign<A>, A: Clone + Add<Output = A> + Conjugable + Mul<Output = A>> Mul for Complex<A, S> {
     type Output = Self;
     #[inline] fn mul(self, Complex(_, c, d): Self) -> Self {
         let Complex(_, a, b) = self;
-        Complex(PhantomData, a*c+S::sign(d.conjugate()*b), d*a+b*c.conjugate())
+        Complex(PhantomData, a.clone()*c.clone()+S::sign(d.clone().conjugate()*b.clone()), d*a+b*c.conjugate())
     }
 }
 
-impl<S: Sign<A>, A: Copy + Add<Output = A> + Neg<Output = A> + Conjugable + Mul<Output = A> + Div<Output = A>> Div for Complex<A, S> {
+/// Right division, `x / y = x y⁻¹`; see `div_left` for `y⁻¹ x`
+///
+/// Over `f32` and `f64`, this is by an algorithm robust to overflow and underflow, which is why `A` must be `'static`.
+impl<S: Sign<A>, A: 'static + Clone + Add<Output = A> + Neg<Output = A> + Conjugable + Mul<Output = A> + Div<Output = A>> Div for Complex<A, S> {
     type Output = Self;
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    #[inline]
-    fn div(self, other: Self) -> Self {
-        let Complex(_, a, b) =  self*other.conjugate();
-        let Complex(_, c, _) = other*other.conjugate();
-        Complex(PhantomData, a/c, b/c)
-    }
+    #[inline] fn div(self, other: Self) -> Self { div::div(self, other) }
+}
+
+/// In commutative algebras such as the complex numbers these agree; from the quaternions up, in general they do not.
+impl<S: Sign<A>, A> Complex<A, S> where Self: Conjugable + Div<Output = Self> {
+    /// `x y⁻¹`, the same as `x / y`
+    #[inline] pub fn div_right(self, other: Self) -> Self { self / other }
+
+    /// `y⁻¹ x`, computed as `(x* / y*)*`
+    #[inline] pub fn div_left(self, other: Self) -> Self { (self.conjugate() / other.conjugate()).conjugate() }
+}
+
+macro_rules! impl_Complex_binop_ref {
+    ($($tr: ident, $f: ident);*) => ($(
+        impl<'a, S: Sign<A>, A: Clone> $tr<&'a Complex<A, S>> for Complex<A, S> where Self: $tr<Output = Self> {
+            type Output = Self;
+            #[inline] fn $f(self, other: &'a Self) -> Self { self.$f(other.clone()) }
+        }
+
+        impl<'a, S: Sign<A>, A: Clone> $tr<Complex<A, S>> for &'a Complex<A, S> where Complex<A, S>: $tr<Output = Complex<A, S>> {
+            type Output = Complex<A, S>;
+            #[inline] fn $f(self, other: Complex<A, S>) -> Complex<A, S> { self.clone().$f(other) }
+        }
+
+        impl<'a, 'b, S: Sign<A>, A: Clone> $tr<&'b Complex<A, S>> for &'a Complex<A, S> where Complex<A, S>: $tr<Output = Complex<A, S>> {
+            type Output = Complex<A, S>;
+            #[inline] fn $f(self, other: &'b Complex<A, S>) -> Complex<A, S> { self.clone().$f(other.clone()) }
+        }
+    )*);
+}
+impl_Complex_binop_ref!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl<S: Sign<A>, A: Clone> Neg for &Complex<A, S> where Complex<A, S>: Neg<Output = Complex<A, S>> {
+    type Output = Complex<A, S>;
+    #[inline] fn neg(self) -> Complex<A, S> { self.clone().neg() }
+}
+
+macro_rules! impl_Complex_assign_componentwise {
+    ($($tr: ident, $f: ident);*) => ($(
+        impl<S: Sign<A>, A: $tr> $tr for Complex<A, S> {
+            #[inline] fn $f(&mut self, Complex(_, c, d): Self) { self.1.$f(c); self.2.$f(d); }
+        }
+
+        impl<'a, S: Sign<A>, A: $tr<&'a A>> $tr<&'a Complex<A, S>> for Complex<A, S> {
+            #[inline] fn $f(&mut self, Complex(_, c, d): &'a Self) { self.1.$f(c); self.2.$f(d); }
+        }
+    )*);
+}
+impl_Complex_assign_componentwise!(AddAssign, add_assign; SubAssign, sub_assign);
+
+macro_rules! impl_Complex_assign_by_op {
+    ($($tr: ident, $f: ident, $op: ident, $g: ident);*) => ($(
+        impl<S: Sign<A>, A: Clone> $tr for Complex<A, S> where Self: $op<Output = Self> {
+            #[inline] fn $f(&mut self, other: Self) { *self = self.clone().$g(other); }
+        }
+
+        impl<'a, S: Sign<A>, A: Clone> $tr<&'a Complex<A, S>> for Complex<A, S> where Self: $op<Output = Self> {
+            #[inline] fn $f(&mut self, other: &'a Self) { *self = self.clone().$g(other.clone()); }
+        }
+    )*);
 }
+impl_Complex_assign_by_op!(MulAssign, mul_assign, Mul, mul; DivAssign, div_assign, Div, div);
 
 /// Wraps a type to make it opaque to conjugation, i.e. `SelfConjugate(a).conjugate() = SelfConjugate(a)`.
 ///
-/// It can be used to construct higher-order hypercomplex numbers, for example: the dual quaternion type over `A` is `Dual<SelfConjugate<Quaternion<A>>>`.
+/// It can be used to construct higher-order hypercomplex numbers, for example: the dual quaternion type over `A` is `Quaternion<SelfConjugate<Dual<A>>>`.
+///
+/// The wrapped type should be commutative, as the construction is not associative over a non-commutative base: `Dual<SelfConjugate<Quaternion<A>>>` is not the dual quaternion type.
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SelfConjugate<A>(pub A);
@@ -140,12 +260,51 @@ impl<A> Conjugable for SelfConjugate<A> {
     fn conjugate(self) -> Self { self }
 }
 
+impl<A: Zero> Zero for SelfConjugate<A> { const zero: Self = SelfConjugate(A::zero); }
+impl<A: One>  One  for SelfConjugate<A> { const one : Self = SelfConjugate(A::one); }
+
+macro_rules! impl_SelfConjugate_binop {
+    ($($tr: ident, $f: ident);*) => ($(
+        impl<A: $tr<Output = A>> $tr for SelfConjugate<A> {
+            type Output = Self;
+            #[inline] fn $f(self, SelfConjugate(b): Self) -> Self { SelfConjugate(self.0.$f(b)) }
+        }
+    )*);
+}
+impl_SelfConjugate_binop!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+macro_rules! impl_SelfConjugate_assign {
+    ($($tr: ident, $f: ident);*) => ($(
+        impl<A: $tr> $tr for SelfConjugate<A> {
+            #[inline] fn $f(&mut self, SelfConjugate(b): Self) { self.0.$f(b) }
+        }
+
+        impl<'a, A: $tr<&'a A>> $tr<&'a SelfConjugate<A>> for SelfConjugate<A> {
+            #[inline] fn $f(&mut self, SelfConjugate(b): &'a Self) { self.0.$f(b) }
+        }
+    )*);
+}
+impl_SelfConjugate_assign!(AddAssign, add_assign; SubAssign, sub_assign; MulAssign, mul_assign; DivAssign, div_assign; RemAssign, rem_assign);
+
+impl<A: Neg<Output = A>> Neg for SelfConjugate<A> {
+    type Output = Self;
+    #[inline] fn neg(self) -> Self { SelfConjugate(self.0.neg()) }
+}
+
 #[cfg(test)] mod tests {
     use typenum::consts::P1;
     use typenum::int::Z0;
 
     use super::*;
 
+    #[test] fn const_rect() {
+        const C: Complex<isize> = from_rect(1, 2);
+        const R: (isize, isize) = C.into_rect();
+        const O: Complex<Complex<isize>> = One::one;
+        assert_eq!((1, 2), R);
+        assert_eq!((from_rect(1, 0), from_rect(0, 0)), O.into_rect());
+    }
+
     #[test] fn complex_basis() {
         type T = Complex<isize>;
         let i: T = from_rect(0, 1);
@@ -165,12 +324,1076 @@ impl<A> Conjugable for SelfConjugate<A> {
     }
 
     #[test] fn quaternion_basis() {
-        type T = Complex<Complex<isize>>;
-        let one = from_rect(from_rect(1, 0), from_rect(0, 0));
-        let i: T = from_rect(from_rect(0, 1), from_rect(0, 0));
-        let j: T = from_rect(from_rect(0, 0), from_rect(1, 0));
-        let k: T = from_rect(from_rect(0, 0), from_rect(0, 1));
+        let one = quaternion(1, 0, 0, 0);
+        let i: Quaternion<isize> = quaternion(0, 1, 0, 0);
+        let j = quaternion(0, 0, 1, 0);
+        let k = quaternion(0, 0, 0, 1);
         assert_eq!((i*j, j*k, k*i, k*j, j*i, i*k,  i*i,  j*j,  k*k),
                    ( k,   i,   j,  -i,  -k,  -j,  -one, -one, -one));
     }
+
+    #[test] fn coquaternion_basis() {
+        let one = coquaternion(1, 0, 0, 0);
+        let i: Coquaternion<isize> = coquaternion(0, 1, 0, 0);
+        let j = coquaternion(0, 0, 1, 0);
+        let k = coquaternion(0, 0, 0, 1);
+        assert_eq!((i*j, j*k, k*i, j*i,  i*i, j*j, k*k),
+                   ( k,  -i,   j,  -k,  -one, one, one));
+    }
+
+    #[test] fn octonion_basis() {
+        let e = |n| -> Octonion<isize> { let mut a = [0; 8]; a[n] = 1;
+                                         octonion(a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7]) };
+        for n in 1..8 { assert_eq!(-e(0), e(n)*e(n)); }
+        for m in 1..8 { for n in 1..8 { if m != n { assert_eq!(-(e(m)*e(n)), e(n)*e(m)); } } }
+    }
+
+    #[test] fn dual_quaternion_mul() {
+        let p = dual_quaternion(quaternion(0, 1, 0, 0), quaternion(0, 0, 1, 0));
+        let q = dual_quaternion(quaternion(0, 0, 1, 0), quaternion(0, 0, 0, 1));
+        assert_eq!(dual_quaternion(quaternion(0, 0, 0, 1), quaternion(-1, 0, -1, 0)), p*q);
+        assert_eq!(dual_quaternion(quaternion(0, 0, 0, -1), quaternion(-1, 0, 1, 0)), q*p);
+    }
+
+    #[test] fn hyper_dual_derivatives() {
+        type T = Dual<SelfConjugate<Dual<isize>>>;
+        let x: T = dual(SelfConjugate(dual(2, 1)), SelfConjugate(dual(1, 0)));
+        assert_eq!(dual(SelfConjugate(dual(8, 12)), SelfConjugate(dual(12, 12))), x*x*x);
+    }
+
+    #[test] fn norm_sqr() {
+        assert_eq!(30, quaternion(1, 2, 3, 4).norm_sqr());
+        assert_eq!(204, octonion(1, 2, 3, 4, 5, 6, 7, 8).norm_sqr());
+        assert_eq!(5, split_complex(3, 2).norm_sqr());
+        assert_eq!(9, dual(3, 5).norm_sqr());
+        assert_eq!(-20, coquaternion(1, 2, 3, 4).norm_sqr());
+        assert_eq!(SelfConjugate(dual(1, 0)), dual_quaternion(quaternion(0, 1, 0, 0), quaternion(0, 0, 1, 0)).norm_sqr());
+    }
+
+    #[test] fn recip() {
+        let q = quaternion(1., 1., 1., 1.);
+        assert_eq!(quaternion(0.25, -0.25, -0.25, -0.25), q.recip());
+        assert_eq!(quaternion(1., 0., 0., 0.), q*q.recip());
+        assert_eq!(from_rect(0., -1.), Complex::<f64>::from_rect(0., 1.).recip());
+        assert_eq!(split_complex(-0.1875, 0.3125), split_complex(3., 5.).recip());
+    }
+
+    #[cfg(feature = "std")]
+    #[test] fn normalize() {
+        let q = quaternion(2., 2., 2., 2.);
+        assert_eq!(4., q.norm());
+        assert_eq!(quaternion(0.5, 0.5, 0.5, 0.5), q.normalize());
+    }
+
+    #[test] fn assign_ops() {
+        let xs = [octonion(1, 2, 3, 4, 5, 6, 7, 8), octonion(0, -1, 0, 2, 0, -3, 0, 4), octonion(2, 0, 0, 0, 0, 0, 0, 1)];
+        let (mut sum, mut diff, mut prod) = (Octonion::zero, Octonion::zero, Octonion::one);
+        for x in &xs { sum += x; diff -= *x; prod *= x; }
+        assert_eq!(xs[0] + xs[1] + xs[2], sum);
+        assert_eq!(-xs[0] - xs[1] - xs[2], diff);
+        assert_eq!(xs[0] * xs[1] * xs[2], prod);
+
+        let mut q = quaternion(1., 2., 3., 4.);
+        q *= quaternion(0., 1., 0., 0.);
+        q /= quaternion(0., 1., 0., 0.);
+        assert_eq!(quaternion(1., 2., 3., 4.), q);
+    }
+
+    #[allow(clippy::op_ref)]
+    #[test] fn ref_ops() {
+        let p = quaternion(1, 2, 3, 4);
+        let q = quaternion(0, -1, 5, 2);
+        assert_eq!(p + q, &p + &q);
+        assert_eq!(p - q, &p - q);
+        assert_eq!(p * q, p * &q);
+        assert_eq!(p * q, &p * &q);
+        assert_eq!(-p, -&p);
+    }
+
+    #[test] fn scalar_ops() {
+        let q = quaternion(1., 2., 3., 4.);
+        assert_eq!(quaternion(2., 4., 6., 8.), 2.*q);
+        assert_eq!(quaternion(2., 4., 6., 8.), q*2.);
+        assert_eq!(quaternion(0.5, 1., 1.5, 2.), q/2.);
+        assert_eq!(octonion(1, 2, 3, 4, 5, 6, 7, 8), octonion(3, 6, 9, 12, 15, 18, 21, 24)/3);
+        assert_eq!(q.recip()*2., 2./q);
+        assert_eq!(split_complex(2., 0.)/split_complex(3., 5.), 2./split_complex(3., 5.));
+        let (a, b) = (2./split_complex::<f64>(3., 5.)).into_rect();
+        assert!((a + 0.375).abs() <= f64::EPSILON && b == 0.625);
+        assert_eq!(from_rect(1e-200, 0.), 1./Complex::<f64>::from_rect(1e200, 0.));
+
+        let mut p = q;
+        p *= 4.;
+        p /= 2.;
+        assert_eq!(2.*q, p);
+
+        let d = dual_quaternion(quaternion(1, 0, 0, 0), quaternion(0, 1, 0, 0));
+        assert_eq!(dual_quaternion(quaternion(3, 0, 0, 0), quaternion(0, 3, 0, 0)), d*3);
+    }
+
+    /// Exact rational, deliberately not `Copy`
+    #[derive(Debug, Clone, PartialEq)]
+    struct Q(i64, i64);
+
+    impl Q {
+        fn new(n: i64, d: i64) -> Self {
+            fn gcd(a: i64, b: i64) -> i64 { if 0 == b { a.abs() } else { gcd(b, a % b) } }
+            let g = gcd(n, d) * d.signum();
+            Q(n/g, d/g)
+        }
+    }
+
+    impl Zero for Q { const zero: Self = Q(0, 1); }
+    impl One  for Q { const one : Self = Q(1, 1); }
+    impl Conjugable for Q { fn conjugate(self) -> Self { self } }
+    impl Add for Q { type Output = Q; fn add(self, Q(c, d): Q) -> Q { Q::new(self.0*d + c*self.1, self.1*d) } }
+    impl Sub for Q { type Output = Q; fn sub(self, Q(c, d): Q) -> Q { Q::new(self.0*d - c*self.1, self.1*d) } }
+    impl Mul for Q { type Output = Q; fn mul(self, Q(c, d): Q) -> Q { Q::new(self.0*c, self.1*d) } }
+    impl Div for Q { type Output = Q; fn div(self, Q(c, d): Q) -> Q { Q::new(self.0*d, self.1*c) } }
+    impl Neg for Q { type Output = Q; fn neg(self) -> Q { Q(-self.0, self.1) } }
+    impl Invertible for Q { fn is_invertible(&self) -> bool { 0 != self.0 } }
+
+    impl Norm for Q {
+        type Real = Q;
+        fn norm_sqr(&self) -> Q { self.clone() * self.clone() }
+        fn unscale(self, k: &Q) -> Q { self / k.clone() }
+    }
+
+    impl Hypercomplex for Q {
+        type Scalar = Q;
+        const DIM: usize = 1;
+        fn component(&self, _: usize) -> &Q { self }
+        fn component_mut(&mut self, _: usize) -> &mut Q { self }
+        fn components(&self) -> impl Iterator<Item = &Q> { core::iter::once(self) }
+        fn components_mut(&mut self) -> impl Iterator<Item = &mut Q> { core::iter::once(self) }
+        fn from_components<I: Iterator<Item = Q>>(it: &mut I) -> Option<Q> { it.next() }
+    }
+
+    #[test] fn clone_only_scalar() {
+        let z: Complex<Q> = from_rect(Q::new(1, 1), Q::new(2, 1));
+        let w: Complex<Q> = from_rect(Q::new(3, 1), Q::new(-1, 1));
+        assert_eq!(from_rect(Q::new(5, 1), Q::new(5, 1)), z.clone()*w.clone());
+        assert_eq!(z.clone(), z.clone()*w.clone()/w.clone());
+        assert_eq!(from_rect(Q::new(1, 10), Q::new(7, 10)), z/w);
+        assert_eq!(from_rect(Q::new(1, 2), Q::zero), Complex::<Q>::from(Q::new(1, 2)));
+
+        let p = quaternion(Q::new(1, 1), Q::new(2, 1), Q::new(3, 1), Q::new(4, 1));
+        let q = quaternion(Q::new(1, 2), Q::zero, Q::new(-1, 1), Q::new(3, 1));
+        assert_eq!(p.clone(), p.clone()*q.clone()/q.clone());
+        let mut r = p.clone();
+        r *= &q;
+        r /= q;
+        assert_eq!(p, r);
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test] fn big_integer_scalar() {
+        use num_bigint::BigInt;
+
+        let k = BigInt::from(10).pow(30);
+        let z: Complex<BigInt> = from_rect(BigInt::from(3) * &k, BigInt::from(4) * &k);
+        let w: Complex<BigInt> = from_rect(BigInt::from(1), BigInt::from(-2));
+        assert_eq!(from_rect(BigInt::from(11) * &k, BigInt::from(-2) * &k), z.clone() * w.clone());
+        assert_eq!(z.clone(), z * w.clone() / w);
+    }
+
+    #[cfg(all(feature = "num-bigint", feature = "num-rational"))]
+    #[test] fn big_rational_scalar() {
+        use num_bigint::BigInt;
+        use num_rational::Ratio;
+
+        let r = |n: i64, d: i64| Ratio::new(BigInt::from(n), BigInt::from(d));
+        let p = quaternion(r(1, 2), r(2, 3), r(-3, 4), r(4, 5));
+        let q = quaternion(r(1, 1), r(0, 1), r(-1, 7), r(3, 1));
+        assert_eq!(p.clone(), p.clone() * q.clone() / q.clone());
+        assert_eq!(quaternion(r(1, 1), r(0, 1), r(0, 1), r(0, 1)), q.clone() / q);
+    }
+
+    #[allow(clippy::excessive_precision)]
+    #[test] fn robust_div() {
+        fn p2(k: i32) -> f64 {
+            if k >= -1022 { f64::from_bits(((k + 1023) as u64) << 52) } else { f64::from_bits(1 << (k + 1074)) }
+        }
+        fn near(x: f64, y: f64) -> bool { x == y || ((x - y) / y).abs() <= 4. * f64::EPSILON }
+
+        // Baudin and Smith's hard cases
+        let cases = [
+            ((1., 1.), (1., p2(1023)), (p2(-1023), -p2(-1023))),
+            ((1., 1.), (p2(-1023), p2(-1023)), (p2(1023), 0.)),
+            ((p2(1023), p2(-1023)), (p2(677), p2(-677)), (p2(346), -p2(-1008))),
+            ((p2(1023), p2(1023)), (1., 1.), (p2(1023), 0.)),
+            ((p2(1020), p2(-844)), (p2(656), p2(-780)), (p2(364), -p2(-1072))),
+            ((p2(-71), p2(1021)), (p2(1001), p2(-323)), (p2(-1072), p2(20))),
+            ((p2(-347), p2(-54)), (p2(-1037), p2(-1058)), (3.8981256045591133e289, 8.1749619078523536e295)),
+            ((p2(-1074), p2(-1074)), (p2(-1073), p2(-1074)), (0.6, 0.2)),
+            ((p2(1015), p2(-989)), (p2(1023), p2(1023)), (0.001953125, -0.001953125)),
+            ((p2(-622), p2(-1071)), (p2(-343), p2(-798)), (1.0295115178936058e-84, 6.9714598751507623e-220)),
+        ];
+        for &((a, b), (c, d), (e, f)) in cases.iter() {
+            let (x, y) = (Complex::<f64>::from_rect(a, b) / from_rect(c, d)).into_rect();
+            assert!(near(x, e) && near(y, f), "({:e}+{:e}i)/({:e}+{:e}i) = {:e}+{:e}i, not {:e}+{:e}i", a, b, c, d, x, y, e, f);
+        }
+
+        let big = 1e300f64;
+        assert_eq!(split_complex(0.5, 0.), split_complex(2. * big, big) / split_complex(4. * big, 2. * big));
+        let tiny = p2(-1000);
+        assert_eq!(dual(2., -1.), dual(2. * tiny, 3. * tiny) / dual(tiny, 2. * tiny));
+        assert_eq!(Complex::<f32>::from_rect(1., 0.), Complex::<f32>::from_rect(3e38, 3e38) / from_rect(3e38, 3e38));
+        assert_eq!(Complex::<i32>::from_rect(1, 2), Complex::<i32>::from_rect(-5, 10) / from_rect(3, 4));
+    }
+
+    #[test] fn checked_div() {
+        let z: Complex<f64> = from_rect(3., 4.);
+        assert_eq!(Some(from_rect(0.12, -0.16)), z.checked_inv());
+        assert_eq!(None, Complex::<f64>::zero.checked_inv());
+        assert_eq!(Err(NotInvertible), z.try_div(Complex::zero));
+        assert_eq!(Ok(from_rect(1., 0.)), z.try_div(z));
+        assert!(!z.is_zero_divisor() && !Complex::<f64>::zero.is_zero_divisor());
+
+        // Over the integers, only elements of unit norm are invertible, even where the quotient is exact.
+        assert_eq!(None, Complex::<i32>::from_rect(1, 2).checked_div(Complex::zero));
+        assert_eq!(None, Complex::<i32>::from_rect(-5, 10).checked_div(from_rect(3, 4)));
+        assert_eq!(Some(from_rect(10, 5)), Complex::<i32>::from_rect(-5, 10).checked_div(from_rect(0, 1)));
+        assert_eq!(None, Complex::<i32>::from_rect(1, 2).checked_inv());
+        assert_eq!(None, Complex::<i32>::from_rect(50000, 0).checked_inv());
+        let q = quaternion(12i8, 1, 0, 0);
+        assert!(!q.is_invertible() && !q.is_zero_divisor());
+        assert_eq!(None, q.checked_inv());
+
+        let (x, y) = (split_complex(1, 1), split_complex(1, -1));
+        assert!(!x.is_invertible() && x.is_zero_divisor());
+        assert_eq!(SplitComplex::zero, x * y);
+        assert_eq!(Err(NotInvertible), split_complex(2, 3).try_div(x));
+        assert_eq!(None, dual(0., 1.).checked_inv());
+        assert!(dual(0., 1.).is_zero_divisor() && !dual(1., 1.).is_zero_divisor());
+
+        let c = coquaternion(1., 0., 1., 0.);
+        assert!(!c.is_invertible() && c.is_zero_divisor());
+        assert_eq!(None, coquaternion(1., 2., 3., 4.).checked_div(c));
+        let o = split_octonion(1, 0, 0, 0, 0, 0, 0, 1);
+        assert!(!o.is_invertible() && o.is_zero_divisor());
+
+        // Scaled so the norm neither overflows nor underflows
+        assert_eq!(Ok(from_rect(1e200, 1e200)), Complex::<f64>::from_rect(1., 1.).try_div(from_rect(1e-200, 0.)));
+        assert_eq!(Some(from_rect(1e-200, 0.)), Complex::<f64>::from_rect(1e200, 0.).checked_inv());
+        assert!(split_complex(1e-200, 1e-200).is_zero_divisor() && !split_complex(1e-200, 2e-200).is_zero_divisor());
+        assert!(split_complex(100i8, 100).is_zero_divisor() && !split_complex(10i8, 9).is_zero_divisor());
+        assert!(!quaternion(200i32, 200, 200, 200).is_zero_divisor());
+        assert!(!octonion(100i32, 2, 3, 4, 5, 6, 7, 8).is_zero_divisor());
+        assert!(!quaternion(10i8, 10, 10, 10).is_zero_divisor() && !quaternion(10i8, 10, 10, 10).is_invertible());
+        // Units whose norm overflows in `i8` as it is summed, and one whose inverse is not representable
+        assert_eq!(Some(coquaternion(1i8, -12, 0, -12)), coquaternion(1i8, 12, 0, 12).checked_inv());
+        assert!(coquaternion(1i8, -128, 0, -128).is_invertible());
+        assert_eq!(None, coquaternion(1i8, -128, 0, -128).checked_inv());
+        let u = dual_quaternion(quaternion(0i32, 1, 0, 0), quaternion(2, 3, 4, 5));
+        assert!(u.is_invertible() && !u.is_zero_divisor());
+        assert_eq!(Some(DualQuaternion::one), u.checked_inv().map(|r| u * r));
+        assert_eq!(None, dual_quaternion(quaternion(2i32, 0, 0, 0), Quaternion::zero).checked_inv());
+
+        let eps = dual_quaternion(Quaternion::zero, quaternion(0., 1., 0., 0.));
+        assert!(!eps.is_invertible() && eps.is_zero_divisor());
+        let q = dual_quaternion(quaternion(1., 1., 1., 1.), quaternion(0., 1., 0., 0.));
+        assert!(q.is_invertible() && !q.is_zero_divisor());
+        assert_eq!(None, q.checked_div(eps));
+        assert_eq!(Some(DualQuaternion::one), q.checked_inv().map(|r| q * r));
+
+        // Any scalar which is `Hypercomplex` and `Invertible`
+        let q = quaternion(Q::new(1, 2), Q::new(0, 1), Q::new(1, 3), Q::new(0, 1));
+        assert_eq!(Some(Quaternion::one), q.clone().checked_inv().map(|r| q.clone() * r));
+        assert!(!q.is_zero_divisor() && split_complex(Q::new(1, 2), Q::new(-1, 2)).is_zero_divisor());
+    }
+
+    #[test] fn sedenion_zero_divisors() {
+        let (e, f) = (Sedenion::<i64>::basis, Sedenion::<f64>::basis);
+        for &(a, b, c, d, s) in [(1, 10, 4, 15, -1), (1, 10, 5, 14, 1), (1, 11, 6, 12, -1), (1, 12, 2, 15, 1)].iter() {
+            let (x, y) = (e(a) + e(b), e(c) + e(d) * s);
+            assert_eq!(Sedenion::zero, x * y);
+            assert!(x.is_zero_divisor() && y.is_zero_divisor());
+            // Invertible over the reals, but not over the integers, as the norm is 2
+            assert!((f(a) + f(b)).is_invertible() && (f(c) + f(d) * s as f64).is_invertible());
+            assert!(!x.is_invertible() && !y.is_invertible());
+        }
+
+        // All the pairs of the form `(e_a + e_b)(e_c ± e_d) = 0`
+        let mut n = 0;
+        for a in 1..16 { for b in a+1..16 {
+            let x = e(a) + e(b);
+            let mut divides = false;
+            for c in 1..16 { for d in c+1..16 { for &s in [1, -1].iter() {
+                if x * (e(c) + e(d) * s) == Sedenion::zero { divides = true; n += 1; }
+            } } }
+            assert_eq!(divides, x.is_zero_divisor(), "e{} + e{}", a, b);
+        } }
+        assert_eq!(168, n);
+
+        // Exact however large the components
+        for &m in [i64::MAX, i64::MIN].iter() { assert!((e(1) * m + e(10) * m).is_zero_divisor()); }
+        assert!(!(e(1) * i64::MAX + e(10) * (i64::MAX - 1)).is_zero_divisor());
+        assert!(!(e(1) * (i64::MIN + 1) + e(10) * (i64::MIN + 2)).is_zero_divisor());
+        for &(a, b) in [(3, 3), (3, 2), (-5, 5)].iter() {
+            assert_eq!((e(1) * a + e(10) * b).is_zero_divisor(), (f(1) * a as f64 + f(10) * b as f64).is_zero_divisor());
+        }
+
+        assert!(!(e(0) + e(10)).is_zero_divisor());
+        assert!(!octonion(1., 2., 3., 4., 5., 6., 7., 8.).is_zero_divisor());
+        assert!(!Sedenion::<f64>::basis(3).is_zero_divisor());
+        assert!((Sedenion::<f64>::basis(3) + Sedenion::basis(10)).is_zero_divisor());
+    }
+
+    #[test] fn left_right_div() {
+        let (x, y) = (quaternion(1., 2., 3., 4.), quaternion(1., 1., 1., 1.));
+        assert_eq!(x / y, x.div_right(y));
+        assert_ne!(x.div_left(y), x.div_right(y));
+        assert_eq!(x, x.div_right(y) * y);
+        assert_eq!(x, y * x.div_left(y));
+        assert_eq!(quaternion(2.5, 0.5, 0., 1.), x.div_right(y));
+        assert_eq!(quaternion(2.5, 0., 1., 0.5), x.div_left(y));
+
+        let (z, w): (Complex<f64>, Complex<f64>) = (from_rect(-5., 10.), from_rect(3., 4.));
+        assert_eq!(z.div_left(w), z.div_right(w));
+        assert_eq!(from_rect(1., 2.), z.div_left(w));
+
+        let o = octonion(1, 2, 3, 4, 5, 6, 7, 8);
+        let e = Octonion::<i32>::basis(5);
+        assert_ne!(o.div_left(e), o.div_right(e));
+        assert_eq!(o, e * o.div_left(e));
+        assert_eq!(o, o.div_right(e) * e);
+    }
+
+    #[test] fn display() {
+        use std::format;
+
+        let z: Complex<f64> = from_rect(1., 2.);
+        assert_eq!("1+2i", format!("{}", z));
+        assert_eq!("1-2i", format!("{}", z.conjugate()));
+        assert_eq!("-1+0i", format!("{}", Complex::<i32>::from_rect(-1, 0)));
+        assert_eq!("1-2i+3j+0.5k", format!("{}", quaternion(1., -2., 3., 0.5)));
+        assert_eq!("1*e0+2*e1-3*e2+4*e3+0*e4+0*e5-7*e6+8*e7", format!("{}", octonion(1, 2, -3, 4, 0, 0, -7, 8)));
+        assert_eq!("1-1j", format!("{}", split_complex(1, -1).labelled(&["", "j"])));
+        assert_eq!("1+2ε", format!("{}", dual(1, 2).labelled(&["", "ε"])));
+        assert_eq!("1+2ε", format!("{}", dual(1, 2).labelled(&[""])));
+        assert_eq!("1-1j", format!("{}", split_complex(1, -1)));
+        assert_eq!("1+2ε", format!("{}", dual(1, 2)));
+        assert_eq!("1+2i+3j+4k", format!("{}", coquaternion(1, 2, 3, 4)));
+        assert_eq!("1+2*e1", format!("{}", dual(1, 2).labelled(&["", "e1"])));
+        assert_eq!("1+2i+3ε+4iε", format!("{}", DualComplex::<i32>::from_array([1, 2, 3, 4])));
+        assert_eq!("1+2j+3i+4ji", format!("{}", Complex::<SplitComplex<i32>>::from_rect(split_complex(1, 2), split_complex(3, 4))));
+        assert_eq!("1+5ε+2i+6εi+3j+7εj+4k+8εk", format!("{}", dual_quaternion(quaternion(1, 2, 3, 4), quaternion(5, 6, 7, 8))));
+        assert_eq!("1*e0+0*e1+0*e2+0*e3", format!("{}", Complex::<Dual<i32>, Z0>::one));
+
+        assert_eq!("1.00+2.00i", format!("{:.2}", z));
+        assert_eq!("+1+2i", format!("{:+}", z));
+        assert_eq!("    1+2i", format!("{:8}", z));
+        assert_eq!("1+2i****", format!("{:*<8}", z));
+        assert_eq!("__1+2i__", format!("{:_^8}", z));
+        assert_eq!("1+2i", format!("{:2}", z));
+        assert_eq!("1e0+2e0i", format!("{:e}", z));
+        assert_eq!("1.5e3-2.0e-3i", format!("{:.1e}", Complex::<f64>::from_rect(1500., -0.002)));
+        assert_eq!("1e0*e0+0e0*e1+0e0*e2+0e0*e3+0e0*e4+0e0*e5+0e0*e6+0e0*e7", format!("{:e}", Octonion::<f64>::one));
+        assert_eq!("inf-0i", format!("{}", Complex::<f64>::from_rect(f64::INFINITY, -0.)));
+        assert_eq!("NaN+NaNi", format!("{}", Complex::<f64>::from_rect(f64::NAN, f64::NAN)));
+
+        assert_eq!("(1.0, 2.0)", format!("{:?}", z));
+        assert_eq!("((1, 2), (3, 4))", format!("{:?}", quaternion(1, 2, 3, 4)));
+    }
+
+    #[test] fn parse() {
+        use std::format;
+        use ParseHypercomplexError::*;
+
+        assert_eq!(Ok(from_rect(3., -4.)), "3-4i".parse::<Complex<f64>>());
+        assert_eq!(Ok(from_rect(-2.5e3, 1.)), "-2.5e3+1j".parse::<Complex<f64>>());
+        assert_eq!(Ok(from_rect(0., 1.)), "i".parse::<Complex<f64>>());
+        assert_eq!(Ok(from_rect(0., 1.)), "1i".parse::<Complex<f64>>());
+        assert_eq!(Ok(from_rect(0., -1.)), " - i ".parse::<Complex<f64>>());
+        assert_eq!(Ok(from_rect(7., 0.)), "7".parse::<Complex<f64>>());
+        assert_eq!(Ok(from_rect(2., 1.)), "i+2".parse::<Complex<f64>>());
+        assert_eq!(Ok(quaternion(1., 2., -3., 4.)), "1 + 2i - 3j + 4k".parse::<Quaternion<f64>>());
+        assert_eq!(Ok(quaternion(0., 0., 1., 0.)), "j".parse::<Quaternion<f64>>());
+        assert_eq!(Ok(octonion(1, 0, 0, 0, 0, 0, 0, -2)), "1*e0 - 2 * e7".parse::<Octonion<i32>>());
+        assert_eq!(Err(InvalidNumber(0)), "2e1+3k".parse::<Octonion<i32>>());
+        assert_eq!(Ok(octonion(20., 0., 0., 3., 0., 0., 0., 0.)), "2e1+3k".parse::<Octonion<f64>>());
+        assert_eq!(Ok(from_rect(-128, 127)), "-128+127i".parse::<Complex<i8>>());
+        assert_eq!(Ok(split_complex(1, -1)), "1-1j".parse::<SplitComplex<i32>>());
+        assert_eq!(Ok(dual(1, 2)), "1+2ε".parse::<Dual<i32>>());
+        assert_eq!(Ok(dual(0, -1)), "-ε".parse::<Dual<i32>>());
+        assert_eq!(Err(UnexpectedToken(2)), "1+ε".parse::<Quaternion<i32>>());
+
+        let (a, b) = "-inf+NaNi".parse::<Complex<f64>>().unwrap().into_rect();
+        assert!(a == f64::NEG_INFINITY && b.is_nan());
+
+        assert_eq!(Err(Empty), "".parse::<Complex<f64>>());
+        assert_eq!(Err(Empty), "  ".parse::<Complex<f64>>());
+        assert_eq!(Err(UnexpectedToken(2)), "1 2i".parse::<Complex<f64>>());
+        assert_eq!(Err(UnexpectedToken(2)), "1+".parse::<Complex<f64>>());
+        assert_eq!(Err(UnexpectedToken(2)), "1*".parse::<Complex<f64>>());
+        assert_eq!(Err(UnexpectedToken(1)), "2e".parse::<Complex<f64>>());
+        assert_eq!(Err(UnexpectedToken(0)), "x".parse::<Complex<f64>>());
+        assert_eq!(Err(InvalidNumber(2)), "1+2.5i".parse::<Complex<i32>>());
+        assert_eq!(Err(DuplicateBasis(1)), "1+2i+3i".parse::<Complex<f64>>());
+        assert_eq!(Err(DuplicateBasis(0)), "1+2i+3".parse::<Complex<f64>>());
+        assert_eq!(Err(WrongDimension(3)), "1+k".parse::<Complex<f64>>());
+        assert_eq!(Err(WrongDimension(8)), "e8".parse::<Octonion<f64>>());
+
+        for x in [quaternion(1., -2., 3., 0.5), quaternion(-0., 1e300, -1e-300, f64::INFINITY)].iter() {
+            assert_eq!(Ok(*x), format!("{}", x).parse());
+            assert_eq!(Ok(*x), format!("{:e}", x).parse());
+            assert_eq!(Ok(*x), format!("{:+12}", x).parse());
+        }
+        let o = octonion(1, 2, -3, 4, 0, 0, -7, 8);
+        assert_eq!(Ok(o), format!("{}", o).parse());
+        let (s, d) = (split_complex(1.5, -2.), dual(-0.5, 3.));
+        assert_eq!((Ok(s), Ok(d)), (format!("{:+}", s).parse(), format!("{:e}", d).parse()));
+        let c = DualComplex::<i32>::from_array([1, -2, 3, -4]);
+        let t = Complex::<SplitComplex<i32>>::from_rect(split_complex(1, 2), split_complex(-3, 4));
+        let q = dual_quaternion(quaternion(1, 2, 3, 4), quaternion(5, -6, 7, 8));
+        assert_eq!((Ok(c), Ok(t), Ok(q)), (format!("{}", c).parse(), format!("{}", t).parse(), format!("{}", q).parse()));
+        assert_eq!(Ok(q), "1+5ε+2i-6εi+3j+7εj+4k+8εk".parse());
+    }
+
+    #[test] fn hypercomplex_components() {
+        assert_eq!((1, 2, 4, 8, 16), (f64::DIM, Complex::<f64>::DIM, Quaternion::<f64>::DIM, Octonion::<f64>::DIM, Sedenion::<f64>::DIM));
+        assert_eq!(8, DualQuaternion::<f64>::DIM);
+
+        let q = quaternion(1, 2, 3, 4);
+        assert_eq!([1, 2, 3, 4], q.to_array());
+        assert_eq!(q, Quaternion::from_array([1, 2, 3, 4]));
+        assert_eq!((1, 3, 4), (q[0], *q.component(2), *q.components().last().unwrap()));
+        assert_eq!(None, Quaternion::<isize>::from_components(&mut [1, 2, 3].iter().cloned()));
+
+        let mut o: Octonion<isize> = Octonion::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+        o[5] = 0;
+        for x in o.components_mut() { *x *= 2; }
+        assert_eq!([2, 4, 6, 8, 10, 0, 14, 16], o.to_array());
+
+        let d = dual_quaternion(quaternion(1, 2, 3, 4), quaternion(5, 6, 7, 8));
+        assert_eq!([1, 5, 2, 6, 3, 7, 4, 8], d.to_array());
+    }
+
+    #[test] #[should_panic] fn component_out_of_range() { let _ = quaternion(1, 2, 3, 4)[4]; }
+
+    #[test] fn basis() {
+        assert_eq!(quaternion(0, 0, 1, 0), Quaternion::<isize>::basis(2));
+        assert_eq!(octonion(0, 0, 0, 0, 0, 0, 0, 1), Octonion::<isize>::basis(7));
+        assert_eq!(dual_quaternion(quaternion(0, 0, 0, 0), quaternion(1, 0, 0, 0)), DualQuaternion::<isize>::basis(1));
+    }
+
+    #[test] fn cayley_table() {
+        use std::format;
+
+        assert_eq!("    e0  e1  e2  e3\n\
+                    e0 +e0 +e1 +e2 +e3\n\
+                    e1 +e1 -e0 +e3 -e2\n\
+                    e2 +e2 -e3 -e0 +e1\n\
+                    e3 +e3 +e2 -e1 -e0", format!("{}", CayleyTable::<Quaternion<i8>>::new()));
+        assert_eq!("    e0  e1\n\
+                    e0 +e0 +e1\n\
+                    e1 +e1   0", format!("{}", CayleyTable::<Dual<i8>>::new()));
+
+        let t = CayleyTable::<Coquaternion<i8>>::new();
+        assert_eq!([1, -1, 1, 1], [0, 1, 2, 3].map(|n| t.get(n, n).sign));
+
+        let t = CayleyTable::<Sedenion<i8>>::new();
+        for m in 0..16 {
+            let mut seen = 0u16;
+            for n in 0..16 {
+                let e = t.get(m, n);
+                assert_eq!(1, e.sign.abs());
+                seen |= 1 << e.index;
+                if 0 < m && m != n && 0 < n { assert_eq!(SignedUnit { sign: -e.sign, index: e.index }, t.get(n, m)); }
+            }
+            assert_eq!(!0, seen);
+            assert_eq!(SignedUnit { sign: if 0 == m { 1 } else { -1 }, index: 0 }, t.get(m, m));
+        }
+    }
+
+    #[test] fn embed() {
+        use core::convert::TryFrom;
+
+        let z: Complex<isize> = from_rect(1, 2);
+        let w: Complex<isize> = from_rect(-3, 1);
+        assert_eq!(quaternion(1, 2, 0, 0), Quaternion::<isize>::from(z));
+        assert_eq!(Quaternion::<isize>::from(z*w), Quaternion::<isize>::from(z)*Quaternion::<isize>::from(w));
+        assert_eq!(octonion(1, 2, 3, 4, 0, 0, 0, 0), Octonion::<isize>::from(quaternion(1, 2, 3, 4)));
+        assert_eq!(octonion(1, 2, 0, 0, 0, 0, 0, 0), Octonion::<isize>::from(z));
+        assert_eq!(octonion(7, 0, 0, 0, 0, 0, 0, 0), Octonion::<isize>::from(7));
+        assert_eq!(Sedenion::<isize>::basis(1), Sedenion::<isize>::from(from_rect(0, 1)));
+        assert_eq!(coquaternion(5, 0, 0, 0), Coquaternion::<isize>::from(5));
+
+        assert_eq!(Ok(z), Complex::<isize>::try_from(quaternion(1, 2, 0, 0)));
+        assert_eq!(Err(ProjectionError), Complex::<isize>::try_from(quaternion(1, 2, 0, 4)));
+        assert_eq!(Ok(z), Complex::<isize>::try_from(Sedenion::<isize>::from(z)));
+        assert_eq!(Ok(quaternion(1, 2, 3, 4)), Quaternion::<isize>::try_from(octonion(1, 2, 3, 4, 0, 0, 0, 0)));
+        assert_eq!(Ok(2.), f64::try_from(quaternion(2., 0., 0., 0.)));
+        assert_eq!(Err(ProjectionError), f64::try_from(quaternion(2., 0., -1., 0.)));
+        assert_eq!(Ok(-3), isize::try_from(Sedenion::<isize>::from(-3)));
+        assert_eq!(Ok(4), i32::try_from(dual(4, 0)));
+    }
+
+    #[test] fn accessors() {
+        let mut z: Complex<isize> = from_rect(1, 2);
+        assert_eq!((&1, &2), (z.re(), z.im()));
+        *z.re_mut() += 1;
+        *z.im_mut() = -1;
+        assert_eq!((&2, &-1), z.as_rect());
+        let (re, im) = z.as_rect_mut();
+        ::core::mem::swap(re, im);
+        assert_eq!(from_rect(-1, 2), z);
+
+        let q = quaternion(1, 2, 3, 4);
+        assert_eq!((&from_rect(1, 2), &from_rect(3, 4)), (q.re(), q.im()));
+    }
+
+    #[test] fn map() {
+        let q = quaternion(1, 2, 3, 4);
+        assert_eq!(quaternion(1, 4, 9, 16), q.map(|x| x*x));
+        assert_eq!(split_complex(true, false), split_complex(1, 2).map(|x| 1 == x % 2));
+        assert_eq!(quaternion(0, 4, 0, 8), q.zip_with(quaternion(1, 2, 1, 2), |x, y| x*(y-1)*2));
+        assert_eq!(coquaternion(1., 0.5, 0., 2.), coquaternion(2., 1., 0., 4.).map(|x: f64| x/2.));
+        let d: DualQuaternion<isize> = dual_quaternion(quaternion(1, 0, 0, 0), quaternion(0, 1, 0, 0));
+        assert_eq!(dual_quaternion(quaternion(-1, 0, 0, 0), quaternion(0, -1, 0, 0)), d.map(|x| -x));
+    }
+
+    #[test] fn cast() {
+        let q: Quaternion<f64> = quaternion(1.5f32, 2., 3., 4.).cast();
+        assert_eq!(quaternion(1.5, 2., 3., 4.), q);
+        let z: Complex<f32> = Complex::<i16>::from_rect(-3, 7).cast();
+        assert_eq!(from_rect(-3., 7.), z);
+        assert_eq!(split_complex(1i64, 2), split_complex(1i32, 2).cast::<i64>());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn close<T: Hypercomplex<Scalar = f64>>(x: T, y: T) -> bool {
+        x.components().zip(y.components()).all(|(&a, &b)| Float::abs(a - b) <= 1e-12 * Float::abs(b).max(1.))
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn elementary_complex() {
+        use core::f64::consts::PI;
+
+        type C = Complex<f64>;
+        assert!(close(from_rect(-1., 0.), C::from_rect(0., PI).exp()));
+        assert_eq!(from_rect(0., PI), C::from_rect(-1., 0.).ln());
+        assert_eq!(from_rect(0., -PI), C::from_rect(-1., -0.).ln());
+        assert_eq!(from_rect(0., 2.), C::from_rect(-4., 0.).sqrt());
+        assert_eq!(from_rect(0., -2.), C::from_rect(-4., -0.).sqrt());
+        assert_eq!(from_rect(2., 1.), C::from_rect(3., 4.).sqrt());
+        assert_eq!(C::zero, C::zero.sqrt());
+        assert!(close(from_rect(-1., 0.), C::from_rect(0., 1.).powf(2.)));
+        let z = C::from_rect(0.5, -1.25);
+        assert!(close(z, z.ln().exp()));
+        assert!(close(C::one, z.sin()*z.sin() + z.cos()*z.cos()));
+        assert!(close(C::one, z.cosh()*z.cosh() - z.sinh()*z.sinh()));
+        assert!(close(from_rect(Float::sin(0.5) * Float::cosh(-1.25), Float::cos(0.5) * Float::sinh(-1.25)), z.sin()));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn elementary_hypercomplex() {
+        let q = quaternion(0.5, -1., 2., 0.25);
+        assert!(close(q, q.ln().exp()));
+        assert!(close(q, q.sqrt()*q.sqrt()));
+        assert!(close(q*q*q, q.powf(3.)));
+        assert!(close(Quaternion::one, q.sin()*q.sin() + q.cos()*q.cos()));
+        let u = quaternion(0., 1., 1., 1.) * (1. / Float::sqrt(3f64));
+        assert!(close(u * Float::sin(1.) + Quaternion::<f64>::from(Float::cos(1f64)), u.exp()));
+
+        let o = octonion(1., 0.5, -0.5, 2., 0., -1., 0.25, 3.);
+        assert!(close(o, o.ln().exp()));
+        assert!(close(o, o.sqrt()*o.sqrt()));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn elementary_split_dual() {
+        let x = split_complex(0.5, -0.25);
+        assert!(close(split_complex(Float::exp(0.5) * Float::cosh(-0.25), Float::exp(0.5) * Float::sinh(-0.25)), x.exp()));
+        assert!(close(split_complex(Float::sin(0.5) * Float::cos(-0.25), Float::cos(0.5) * Float::sin(-0.25)), x.sin()));
+        assert!(close(x, x.ln().exp()));
+        assert!(close(x, x.sqrt()*x.sqrt()));
+
+        assert!(close(dual(Float::sin(2.), Float::cos(2.)), dual(2., 1.).sin()));
+        assert!(close(dual(2., 0.25), dual(4., 1.).sqrt()));
+        assert!(close(dual(8., 12.), dual(2., 1.).powf(3.)));
+        assert!(close(dual(Float::ln(2.), 1.5), dual(2., 3.).ln()));
+
+        // Outside the right-hand light cone, `ln` and `sqrt` are NaN, as for negative reals.
+        let nan = |x: SplitComplex<f64>| x.components().all(|c| c.is_nan());
+        assert!(nan(split_complex(0.25, 0.5).ln()) && nan(split_complex(0.25, -0.5).ln()));
+        assert!(nan(split_complex(0.25, 0.5).sqrt()) && nan(split_complex(0.5, 1.).powf(0.5)));
+        assert!(split_complex(-2., 1.).ln().re().is_nan() && split_complex(-1., 0.).ln().re().is_nan());
+
+        let c = coquaternion(1., 0.5, 0.25, -0.125);
+        assert!(close(c, c.ln().exp()));
+        assert!(close(c.exp()*c.exp(), (c*2.).exp()));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn elementary_non_finite() {
+        use core::f64::consts::FRAC_PI_2;
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+        let all_nan = |x: Quaternion<f64>| x.components().all(|c| c.is_nan());
+
+        assert_eq!(from_rect(INF, FRAC_PI_2), Complex::<f64>::from_rect(1., INF).ln());
+        assert_eq!(from_rect(INF, -FRAC_PI_2), Complex::<f64>::from_rect(1., -INF).ln());
+        let (a, b) = Complex::<f64>::from_rect(1., INF).exp().into_rect();
+        assert!(a.is_nan() && b.is_nan());
+        assert_eq!(quaternion(INF, 0., 0., FRAC_PI_2), quaternion(1., 0., 0., INF).ln());
+        assert_eq!(quaternion(INF, 0., 0., 0.), quaternion(INF, 0., 0., 0.).ln());
+        assert!(all_nan(quaternion(1., INF, 2., 0.).exp()));
+        assert!(all_nan(quaternion(1., NAN, 2., 0.).ln()));
+        assert!(all_nan(quaternion(1., NAN, 0., 0.).sqrt()));
+
+        let (a, b) = split_complex(0., INF).ln().into_rect();
+        assert!(a.is_nan() && b.is_nan());
+        let (a, b) = split_complex(INF, 1.).ln().into_rect();
+        assert_eq!((INF, 0.), (a, b));
+        assert_eq!(split_complex(INF, -INF), split_complex(1., -INF).cosh());
+        let (a, b) = dual(1., INF).exp().into_rect();
+        assert!(a.is_nan() && b.is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn polar_complex() {
+        use core::f64::consts::{ FRAC_PI_2, FRAC_PI_4, PI };
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        let z: Complex<f64> = from_rect(3., 4.);
+        assert_eq!((5., Float::atan2(4., 3.)), z.into_polar());
+        assert!(close(z, Complex::<f64>::from_polar(5., Float::atan2(4., 3.))));
+        assert!(close(from_rect(0., 2.), Complex::<f64>::from_polar(2., FRAC_PI_2)));
+        assert_eq!(5e300, Complex::<f64>::from_rect(3e300, 4e300).abs());
+
+        assert_eq!(0., Complex::<f64>::zero.arg());
+        assert_eq!(PI, Complex::<f64>::from_rect(-1., 0.).arg());
+        assert_eq!(-PI, Complex::<f64>::from_rect(-1., -0.).arg());
+        assert_eq!(FRAC_PI_4, Complex::<f64>::from_rect(INF, INF).arg());
+        assert_eq!(INF, Complex::<f64>::from_rect(NAN, -INF).abs());
+        assert!(Complex::<f64>::from_rect(NAN, 1.).arg().is_nan());
+
+        assert_eq!(Complex::<f64>::from_rect(INF, 0.), Complex::<f64>::from_polar(INF, 0.));
+        assert_eq!(Complex::<f64>::from_rect(-INF, -0.), Complex::<f64>::from_polar(-INF, 0.));
+        assert_eq!(Complex::<f64>::zero, Complex::<f64>::from_polar(0., INF));
+        let (a, b) = Complex::<f64>::from_polar(1., INF).into_rect();
+        assert!(a.is_nan() && b.is_nan());
+        let (a, b) = Complex::<f64>::from_polar(NAN, 0.).into_rect();
+        assert!(a.is_nan() && b.is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn polar_split_complex() {
+        const INF: f64 = f64::INFINITY;
+
+        let x = split_complex(5., 3.);
+        assert_eq!(4., x.modulus());
+        let (rho, phi) = x.into_polar().unwrap();
+        assert_eq!(4., rho);
+        assert!(close(x, SplitComplex::from_polar(rho, phi)));
+        let (rho, phi) = split_complex(-5., 3.).into_polar().unwrap();
+        assert_eq!(-4., rho);
+        assert!(close(split_complex(-5., 3.), SplitComplex::from_polar(rho, phi)));
+
+        assert_eq!(4., split_complex(3., 5.).modulus());
+        assert_eq!(None, split_complex(3., 5.).into_polar());
+        assert_eq!(None, split_complex(2., -2.).into_polar());
+        assert_eq!(-INF, split_complex(2., -2.).rapidity());
+        assert_eq!(Some((0., 0.)), SplitComplex::<f64>::zero.into_polar());
+        assert!(Float::abs(split_complex(5e300, 3e300).modulus() / 4e300 - 1.) < 1e-15);
+        assert_eq!(split_complex(INF, 0.), SplitComplex::from_polar(INF, 0.));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn polar_quaternion() {
+        use core::f64::consts::{ FRAC_PI_2, PI };
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+
+        let q = quaternion(1., 2., -2., 4.);
+        let (r, u, theta) = q.into_axis_angle();
+        assert_eq!(5., r);
+        assert!(close(quaternion(0., 2., -2., 4.), quaternion(0., u[0], u[1], u[2]) * Float::sqrt(24f64)));
+        assert!(close(q, Quaternion::from_axis_angle(r, u, theta)));
+
+        assert_eq!((2., [0., 1., 0.], FRAC_PI_2), quaternion(0., 0., 2., 0.).into_axis_angle());
+        assert_eq!((3., [1., 0., 0.], PI), quaternion(-3., 0., 0., 0.).into_axis_angle());
+        assert_eq!((0., [1., 0., 0.], 0.), Quaternion::<f64>::zero.into_axis_angle());
+        assert_eq!((INF, [0., 0., -1.], FRAC_PI_2), quaternion(1., 2., 0., -INF).into_axis_angle());
+        let (r, u, theta) = quaternion(1., NAN, 0., 0.).into_axis_angle();
+        assert!(r.is_nan() && u.iter().all(|c| c.is_nan()) && theta.is_nan());
+
+        assert_eq!(Quaternion::<f64>::zero, Quaternion::from_axis_angle(0., [0., 0., 1.], INF));
+        assert_eq!(quaternion(INF, 0., 0., 0.), Quaternion::from_axis_angle(INF, [0., 0., 1.], 0.));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test] fn annex_g() {
+        use core::f64::consts::{ FRAC_PI_2, FRAC_PI_4, PI };
+        const INF: f64 = f64::INFINITY;
+        const NAN: f64 = f64::NAN;
+        type G = AnnexG<Complex<f64>>;
+        type P = (f64, f64);
+        type Binary = (&'static str, fn(G, G) -> G, P, P, P);
+        type Unary = (&'static str, fn(G) -> G, P, P);
+
+        fn g((a, b): P) -> G { AnnexG(from_rect(a, b)) }
+        fn same(x: f64, y: f64) -> bool { x.is_nan() && y.is_nan() || x == y && x.is_sign_negative() == y.is_sign_negative() }
+        fn check(name: &str, args: &[P], z: G, (e, f): P) {
+            let (x, y) = z.0.into_rect();
+            assert!(same(x, e) && same(y, f), "{}{:?} = ({:?}, {:?}), not ({:?}, {:?})", name, args, x, y, e, f);
+        }
+
+        let binary: &[Binary] = &[
+            ("mul", Mul::mul, (INF, NAN), (1., 0.), (INF, NAN)),
+            ("mul", Mul::mul, (INF, INF), (0., 1.), (-INF, INF)),
+            ("mul", Mul::mul, (NAN, INF), (2., 0.), (NAN, INF)),
+            ("mul", Mul::mul, (1e300, 1e300), (1e300, NAN), (INF, INF)),
+            ("mul", Mul::mul, (1., 0.), (NAN, NAN), (NAN, NAN)),
+            ("mul", Mul::mul, (2., 3.), (4., 5.), (-7., 22.)),
+            ("div", Div::div, (1., 1.), (0., 0.), (INF, INF)),
+            ("div", Div::div, (1., 0.), (-0., 0.), (-INF, NAN)),
+            ("div", Div::div, (INF, NAN), (1., 0.), (INF, NAN)),
+            ("div", Div::div, (1., 1.), (INF, NAN), (0., 0.)),
+            ("div", Div::div, (NAN, 0.), (0., 0.), (NAN, NAN)),
+            ("div", Div::div, (-6., 8.), (0., 2.), (4., 3.)),
+        ];
+        for &(name, op, z, w, r) in binary { check(name, &[z, w], op(g(z), g(w)), r); }
+
+        let unary: &[Unary] = &[
+            ("cexp", G::exp, (0., 0.), (1., 0.)),
+            ("cexp", G::exp, (-0., -0.), (1., -0.)),
+            ("cexp", G::exp, (1., INF), (NAN, NAN)),
+            ("cexp", G::exp, (1., NAN), (NAN, NAN)),
+            ("cexp", G::exp, (INF, 0.), (INF, 0.)),
+            ("cexp", G::exp, (-INF, 1.), (0., 0.)),
+            ("cexp", G::exp, (INF, 1.), (INF, INF)),
+            ("cexp", G::exp, (-INF, INF), (0., 0.)),
+            ("cexp", G::exp, (INF, INF), (INF, NAN)),
+            ("cexp", G::exp, (-INF, NAN), (0., 0.)),
+            ("cexp", G::exp, (INF, NAN), (INF, NAN)),
+            ("cexp", G::exp, (NAN, 0.), (NAN, 0.)),
+            ("cexp", G::exp, (NAN, 1.), (NAN, NAN)),
+            ("cexp", G::exp, (NAN, NAN), (NAN, NAN)),
+
+            ("clog", G::ln, (-0., 0.), (-INF, PI)),
+            ("clog", G::ln, (0., 0.), (-INF, 0.)),
+            ("clog", G::ln, (1., INF), (INF, FRAC_PI_2)),
+            ("clog", G::ln, (1., NAN), (NAN, NAN)),
+            ("clog", G::ln, (-INF, 1.), (INF, PI)),
+            ("clog", G::ln, (INF, 1.), (INF, 0.)),
+            ("clog", G::ln, (-INF, INF), (INF, 3. * FRAC_PI_4)),
+            ("clog", G::ln, (INF, INF), (INF, FRAC_PI_4)),
+            ("clog", G::ln, (INF, NAN), (INF, NAN)),
+            ("clog", G::ln, (NAN, 1.), (NAN, NAN)),
+            ("clog", G::ln, (NAN, INF), (INF, NAN)),
+            ("clog", G::ln, (NAN, NAN), (NAN, NAN)),
+            ("clog", G::ln, (-1., -0.), (0., -PI)),
+
+            ("csqrt", G::sqrt, (0., 0.), (0., 0.)),
+            ("csqrt", G::sqrt, (-0., -0.), (0., -0.)),
+            ("csqrt", G::sqrt, (1., INF), (INF, INF)),
+            ("csqrt", G::sqrt, (NAN, -INF), (INF, -INF)),
+            ("csqrt", G::sqrt, (1., NAN), (NAN, NAN)),
+            ("csqrt", G::sqrt, (-INF, 1.), (0., INF)),
+            ("csqrt", G::sqrt, (INF, 1.), (INF, 0.)),
+            ("csqrt", G::sqrt, (-INF, NAN), (NAN, INF)),
+            ("csqrt", G::sqrt, (INF, NAN), (INF, NAN)),
+            ("csqrt", G::sqrt, (NAN, 1.), (NAN, NAN)),
+            ("csqrt", G::sqrt, (NAN, NAN), (NAN, NAN)),
+            ("csqrt", G::sqrt, (-4., 0.), (0., 2.)),
+            ("csqrt", G::sqrt, (-4., -0.), (0., -2.)),
+
+            ("csinh", G::sinh, (0., 0.), (0., 0.)),
+            ("csinh", G::sinh, (0., INF), (0., NAN)),
+            ("csinh", G::sinh, (0., NAN), (0., NAN)),
+            ("csinh", G::sinh, (1., INF), (NAN, NAN)),
+            ("csinh", G::sinh, (1., NAN), (NAN, NAN)),
+            ("csinh", G::sinh, (INF, 0.), (INF, 0.)),
+            ("csinh", G::sinh, (INF, 1.), (INF, INF)),
+            ("csinh", G::sinh, (INF, INF), (INF, NAN)),
+            ("csinh", G::sinh, (INF, NAN), (INF, NAN)),
+            ("csinh", G::sinh, (NAN, 0.), (NAN, 0.)),
+            ("csinh", G::sinh, (NAN, 1.), (NAN, NAN)),
+            ("csinh", G::sinh, (NAN, NAN), (NAN, NAN)),
+
+            ("ccosh", G::cosh, (0., 0.), (1., 0.)),
+            ("ccosh", G::cosh, (0., INF), (NAN, 0.)),
+            ("ccosh", G::cosh, (0., NAN), (NAN, 0.)),
+            ("ccosh", G::cosh, (1., INF), (NAN, NAN)),
+            ("ccosh", G::cosh, (1., NAN), (NAN, NAN)),
+            ("ccosh", G::cosh, (INF, 0.), (INF, 0.)),
+            ("ccosh", G::cosh, (-INF, 0.), (INF, -0.)),
+            ("ccosh", G::cosh, (INF, 1.), (INF, INF)),
+            ("ccosh", G::cosh, (INF, INF), (INF, NAN)),
+            ("ccosh", G::cosh, (INF, NAN), (INF, NAN)),
+            ("ccosh", G::cosh, (NAN, 0.), (NAN, 0.)),
+            ("ccosh", G::cosh, (NAN, 1.), (NAN, NAN)),
+            ("ccosh", G::cosh, (NAN, NAN), (NAN, NAN)),
+
+            ("csin", G::sin, (0., INF), (0., INF)),
+            ("csin", G::sin, (INF, 0.), (NAN, 0.)),
+            ("ccos", G::cos, (0., INF), (INF, -0.)),
+            ("ccos", G::cos, (INF, 0.), (NAN, -0.)),
+        ];
+        for &(name, f, z, r) in unary { check(name, &[z], f(g(z)), r); }
+
+        // Where the plain formulas give NaN, and the intermediate `e^x` overflows
+        let (re, im) = (Complex::<f64>::from_rect(INF, NAN) * from_rect(1., 0.)).into_rect();
+        assert!(re.is_nan() && im.is_nan());
+        let (re, im) = (g((INF, NAN)) * g((1., 0.))).0.into_rect();
+        assert!(re == INF && im.is_nan());
+        assert!(g((709.9, 0.5)).exp().0.components().all(|c| c.is_finite()));
+        assert!(g((1e308, 1e308)).sqrt().0.components().all(|c| c.is_finite()));
+    }
+
+    #[test] fn macros() {
+        assert_eq!(from_rect(3, 4), c!(3 + 4 i));
+        assert_eq!(from_rect(0, -1), c!(-i));
+        assert_eq!(from_rect(-2, 0), c!(-2));
+        assert_eq!(from_rect(1.5, -0.25), c!(1.5 - 0.25 i));
+        assert_eq!(from_rect(1, 5), c!(2 i + 1 + 3 i));
+        let x = 7;
+        assert_eq!(from_rect(x, -x), c!((x) - (x) i));
+        assert_eq!(quaternion(1, 2, -3, 4), q!(1 + 2 i - 3 j + 4 k));
+        assert_eq!(quaternion(0, 0, 1, 0), q!(j));
+        assert_eq!(quaternion(0., -1., 0., 1.), q!(-i + k));
+        let o: Octonion<i32> = hc![1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], o.to_array());
+        let z: Complex<f64> = hc![1., 2.,];
+        assert_eq!(c!(1. + 2. i), z);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test] fn serde() {
+        use std::string::ToString;
+
+        let z: Complex<f64> = c!(1.5 - 2. i);
+        assert_eq!("[1.5,-2.0]", serde_json::to_string(&z).unwrap());
+        assert_eq!(z, serde_json::from_str::<Complex<f64>>("[1.5, -2]").unwrap());
+        let q: Quaternion<i32> = q!(1 + 2 i - 3 j + 4 k);
+        assert_eq!("[1,2,-3,4]", serde_json::to_string(&q).unwrap());
+        assert_eq!(q, serde_json::from_str(&serde_json::to_string(&q).unwrap()).unwrap());
+        let o: Octonion<i8> = hc![1, -2, 3, -4, 5, -6, 7, -8];
+        assert_eq!(o, serde_json::from_str(&serde_json::to_string(&o).unwrap()).unwrap());
+        let s: SplitComplex<f32> = split_complex(0.5, -0.25);
+        assert_eq!(s, serde_json::from_str(&serde_json::to_string(&s).unwrap()).unwrap());
+
+        let e = serde_json::from_str::<Complex<f64>>("[1.0]").unwrap_err().to_string();
+        assert!(e.starts_with("invalid length 1, expected a sequence of 2 components"), "{}", e);
+        let e = serde_json::from_str::<Complex<f64>>("[1.0, 2.0, 3.0]").unwrap_err().to_string();
+        assert!(e.starts_with("invalid length 3, expected a sequence of 2 components"), "{}", e);
+        assert!(serde_json::from_str::<Quaternion<i32>>("[[1, 2], [3, 4]]").is_err());
+        assert!(serde_json::from_str::<Quaternion<i32>>("[1, 2, 3]").is_err());
+
+        let b = bincode::serialize(&q).unwrap();
+        assert_eq!(16, b.len());
+        assert_eq!(&[1, 0, 0, 0, 2, 0, 0, 0], &b[..8]);
+        assert_eq!(q, bincode::deserialize::<Quaternion<i32>>(&b).unwrap());
+        assert!(bincode::deserialize::<Quaternion<i32>>(&b[..12]).is_err());
+        let z2: Complex<f64> = bincode::deserialize(&bincode::serialize(&z).unwrap()).unwrap();
+        assert_eq!(z, z2);
+    }
+
+    #[test] fn flat() {
+        let mut xs = [c!(1. + 2. i), c!(3. - 4. i)];
+        assert_eq!(&[1., 2., 3., -4.], Complex::<f32>::as_flat(&xs));
+        Complex::as_flat_mut(&mut xs)[3] = 5.;
+        assert_eq!(c!(3. + 5. i), xs[1]);
+
+        let mut buf = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert_eq!(None, Quaternion::<i16>::from_flat(&buf));
+        let qs = Quaternion::<i16>::from_flat(&buf[1..]).unwrap();
+        assert_eq!(&[q!(2 + 3 i + 4 j + 5 k), q!(6 + 7 i + 8 j + 9 k)], qs);
+        Octonion::<i16>::from_flat_mut(&mut buf[..8]).unwrap()[0] = Octonion::zero;
+        assert_eq!([0, 0, 0, 0, 0, 0, 0, 0, 9], buf);
+
+        let ds: &[Quaternion<SelfConjugate<Dual<f64>>>] = Complex::from_flat(&[0.; 16]).unwrap();
+        assert_eq!(2, ds.len());
+        assert_eq!(0, Complex::<f64>::from_flat(&[]).unwrap().len());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test] fn pod() {
+        let xs: [f32; 4] = [1., 2., 3., 4.];
+        let zs: &[Complex<f32>] = bytemuck::cast_slice(&xs);
+        assert_eq!(&[c!(1. + 2. i), c!(3. + 4. i)], zs);
+        let q: Quaternion<f32> = bytemuck::cast(xs);
+        assert_eq!(q!(1. + 2. i + 3. j + 4. k), q);
+        assert_eq!(Octonion::<i32>::zero, bytemuck::Zeroable::zeroed());
+        assert_eq!(&[0, 0, 128, 63], &bytemuck::bytes_of(&AnnexG(q))[..4]);
+    }
+
+    #[test] fn bytes() {
+        let z: Complex<f32> = c!(1. - 2. i);
+        let b: [u8; 8] = z.to_le_bytes();
+        assert_eq!([0, 0, 0x80, 0x3f, 0, 0, 0, 0xc0], b);
+        assert_eq!(z, Complex::from_le_bytes(b));
+        let b: [u8; 8] = z.to_be_bytes();
+        assert_eq!([0x3f, 0x80, 0, 0, 0xc0, 0, 0, 0], b);
+        assert_eq!(z, Complex::from_be_bytes(b));
+
+        let z: Complex<i16> = c!(0x102 - 1 i);
+        assert_eq!([2, 1, 0xff, 0xff], z.to_le_bytes());
+        assert_eq!([1, 2, 0xff, 0xff], z.to_be_bytes());
+
+        let q: Quaternion<f64> = q!(1. + 2. i - 3. j + 0.5 k);
+        assert_eq!(32, Quaternion::<f64>::BYTES);
+        let b: [u8; 32] = q.to_be_bytes();
+        assert_eq!(&(-3f64).to_be_bytes(), &b[16..24]);
+        assert_eq!(q, Quaternion::from_be_bytes(b));
+        let d: Quaternion<SelfConjugate<Dual<i8>>> = hc![1, 2, 3, 4, 5, 6, 7, -8];
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 0xf8], d.to_le_bytes());
+
+        let xs = [c!(1 + 2 i), c!(-3 + 4 i), c!(5 - 6 i)];
+        let mut buf = [0; 24];
+        Complex::<i32>::write_be_bytes(&xs, &mut buf).unwrap();
+        assert_eq!([0, 0, 0, 1, 0, 0, 0, 2, 0xff, 0xff, 0xff, 0xfd], buf[..12]);
+        let mut ys = [Complex::zero; 3];
+        Complex::<i32>::read_be_bytes(&buf, &mut ys).unwrap();
+        assert_eq!(xs, ys);
+        Complex::<i32>::write_le_bytes(&xs, &mut buf).unwrap();
+        Complex::<i32>::read_le_bytes(&buf, &mut ys).unwrap();
+        assert_eq!(xs, ys);
+        assert_eq!(Err(ByteLengthError), Complex::<i32>::write_le_bytes(&xs, &mut buf[1..]));
+        assert_eq!(Err(ByteLengthError), Complex::<i32>::read_le_bytes(&buf, &mut ys[..2]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test] fn iq() {
+        use std::{ format, fs, io, process };
+        use std::io::Write;
+
+        let dir = std::env::temp_dir().join(format!("cplx-iq-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let fixture = |name: &str, bytes: &[u8]| {
+            let path = dir.join(name);
+            fs::File::create(&path).unwrap().write_all(bytes).unwrap();
+            path
+        };
+        let read = |path: &std::path::Path, n: usize| {
+            let ext = path.extension().unwrap().to_str().unwrap();
+            let mut r = IqReader::new(fs::File::open(path).unwrap(), IqFormat::from_extension(ext).unwrap());
+            let mut xs = [Complex::zero; 8];
+            assert_eq!(n, r.read(&mut xs).unwrap());
+            assert_eq!(0, r.read(&mut xs).unwrap());
+            xs
+        };
+
+        let xs = read(&fixture("a.cu8", &[0, 255, 127, 128, 191, 64]), 3);
+        assert_eq!([c!(-0.99609375 + 0.99609375 i), c!(-0.00390625 + 0.00390625 i), c!(0.49609375 - 0.49609375 i)], xs[..3]);
+        let xs = read(&fixture("a.cs8", &[0x80, 0x7f, 0, 0x40]), 2);
+        assert_eq!([c!(-1. + 0.9921875 i), c!(0.5 i)], xs[..2]);
+        let xs = read(&fixture("a.cs16", &[0, 0x80, 0xff, 0x7f, 0, 0x40, 0, 0xc0]), 2);
+        assert_eq!([c!(-1. + (32767. / 32768.) i), c!(0.5 - 0.5 i)], xs[..2]);
+        let xs = read(&fixture("a.cf32", &[0, 0, 0x80, 0x3f, 0, 0, 0, 0xbf]), 1);
+        assert_eq!(c!(1. - 0.5 i), xs[0]);
+
+        let mut r = IqReader::new(&[0x00, 0x80, 0xff, 0x7f, 7][..], IqFormat::Cs16);
+        let mut ys = [Complex::zero; 1];
+        assert_eq!(1, r.read_i16(&mut ys).unwrap());
+        assert_eq!(c!(-32768 + 32767 i), ys[0]);
+        assert_eq!(io::ErrorKind::UnexpectedEof, r.read_i16(&mut ys).unwrap_err().kind());
+        let mut r = IqReader::new(&[0, 255][..], IqFormat::Cu8);
+        assert_eq!(1, r.read_i16(&mut ys).unwrap());
+        assert_eq!(c!(-32640 + 32640 i), ys[0]);
+        // The whole samples before the end, then the error
+        let mut r = IqReader::new(&[0, 255, 127, 128, 5][..], IqFormat::Cu8);
+        let mut ys = [Complex::zero; 3];
+        assert_eq!(2, r.read_i16(&mut ys).unwrap());
+        assert_eq!([c!(-32640 + 32640 i), c!(-128 + 128 i)], ys[..2]);
+        assert_eq!(io::ErrorKind::UnexpectedEof, r.read_i16(&mut ys).unwrap_err().kind());
+        assert_eq!(0, r.read_i16(&mut ys).unwrap());
+
+        let zs = [c!(0.25 - 0.5 i), c!(-1. + 2. i), c!(0.001 + 0. i)];
+        let expected: [(IqFormat, &[u8]); 4] = [
+            (IqFormat::Cu8, &[160, 64, 0, 255, 128, 128]),
+            (IqFormat::Cs8, &[32, 0xc0, 0x80, 0x7f, 0, 0]),
+            (IqFormat::Cs16, &[0, 0x20, 0, 0xc0, 0, 0x80, 0xff, 0x7f, 33, 0, 0, 0]),
+            (IqFormat::Cf32, &[0, 0, 0x80, 0x3e, 0, 0, 0, 0xbf]),
+        ];
+        for &(format, bytes) in &expected {
+            let mut w = IqWriter::new(io::Cursor::new(std::vec::Vec::new()), format);
+            w.write(&zs).unwrap();
+            let out = w.into_inner().into_inner();
+            assert_eq!(bytes, &out[..bytes.len()], "{:?}", format);
+            assert_eq!(3 * format.sample_size(), out.len());
+        }
+
+        let fixtures: [(&str, &[u8]); 4] = [
+            ("b.cu8", &[1, 2, 3, 4, 0, 16, 255, 127]),
+            ("b.cs8", &[1, 2, 3, 4, 0x80, 0x90, 0x7f, 0xff]),
+            ("b.cs16", &[1, 2, 3, 4, 0, 0x80, 0xff, 0x7f]),
+            ("b.cf32", &[0, 0, 0xc0, 0x7f, 1, 0, 0x80, 0xff]),
+        ];
+        for &(name, bytes) in &fixtures {
+            let path = fixture(name, bytes);
+            let format = IqFormat::from_extension(path.extension().unwrap().to_str().unwrap()).unwrap();
+            let n = bytes.len() / format.sample_size();
+            let xs = read(&path, n);
+            let mut w = IqWriter::new(fs::File::create(dir.join("out")).unwrap(), format);
+            w.write(&xs[..n]).unwrap();
+            w.flush().unwrap();
+            assert_eq!(bytes, &fs::read(dir.join("out")).unwrap()[..], "{:?}", format);
+
+            let mut ys = [Complex::zero; 4];
+            IqReader::new(bytes, format).read_i16(&mut ys[..n]).unwrap();
+            let mut w = IqWriter::new(io::Cursor::new(std::vec::Vec::new()), format);
+            w.write_i16(&ys[..n]).unwrap();
+            if format != IqFormat::Cf32 { assert_eq!(bytes, &w.into_inner().into_inner()[..], "{:?}", format) }
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "std")]
+    #[test] fn npy() {
+        use std::{ fmt, io, vec };
+        use std::string::ToString;
+        use std::vec::Vec;
+
+        fn round_trip<T: NpyElement + fmt::Debug + PartialEq>(bytes: &[u8], expected: NpyArray<T>) {
+            let a = NpyArray::<T>::read(bytes).unwrap();
+            assert_eq!(expected, a);
+            let mut out = Vec::new();
+            a.write(&mut out).unwrap();
+            assert_eq!(bytes, &out[..]);
+        }
+
+        let c8 = NpyArray { shape: vec![3], fortran_order: false, data: vec![c!(1. + 2. i), c!(-0.5), c!(-3.25 i)] };
+        round_trip::<Complex<f32>>(include_bytes!("../tests/fixtures/c8.npy"), c8.clone());
+        assert_eq!(c8, NpyArray::read(&include_bytes!("../tests/fixtures/c8_be.npy")[..]).unwrap());
+
+        let data: Vec<Complex<f64>> = (1..7).map(|n| from_rect(n as f64, -n as f64)).collect();
+        round_trip(include_bytes!("../tests/fixtures/c16_2x3.npy"), NpyArray { shape: vec![2, 3], fortran_order: false, data });
+        let data = [1, 4, 2, 5, 3, 6].iter().map(|&n| from_rect(n as f64, -n as f64)).collect();
+        round_trip::<Complex<f64>>(include_bytes!("../tests/fixtures/c16_2x3_fortran.npy"),
+                                   NpyArray { shape: vec![2, 3], fortran_order: true, data });
+        round_trip::<Complex<f64>>(include_bytes!("../tests/fixtures/c16_scalar.npy"),
+                                   NpyArray { shape: vec![], fortran_order: false, data: vec![c!(0.25 + 1e300 i)] });
+
+        round_trip::<Quaternion<f64>>(include_bytes!("../tests/fixtures/quaternion_f8.npy"),
+                                      NpyArray { shape: vec![2], fortran_order: false, data: vec![q!(1. + 2. i + 3. j + 4. k), q!(-1. - 2. i - 3. j - 4. k)] });
+        let data = vec![q!(1. + 2. i + 3. j + 4. k), q!(5. + 6. i + 7. j + 8. k), q!(9. + 10. i + 11. j + 12. k)];
+        round_trip::<Quaternion<f32>>(include_bytes!("../tests/fixtures/quaternion_f4_fortran.npy"),
+                                      NpyArray { shape: vec![3], fortran_order: true, data });
+
+        let c8_bytes = &include_bytes!("../tests/fixtures/c8.npy")[..];
+        let err = |r: io::Result<NpyArray<Complex<f64>>>| r.unwrap_err().to_string();
+        assert_eq!("dtype '<c8' is not 'c16'", err(NpyArray::read(c8_bytes)));
+        assert_eq!("dtype '<c8' is not 'f4'", NpyArray::<Quaternion<f32>>::read(c8_bytes).unwrap_err().to_string());
+        let q_bytes = &include_bytes!("../tests/fixtures/quaternion_f8.npy")[..];
+        let mut transposed = Vec::from(q_bytes);
+        let at = transposed.windows(6).position(|w| w == b"(2, 4)").unwrap();
+        transposed[at..at + 6].copy_from_slice(b"(4, 2)");
+        assert_eq!("last axis of shape not of length 4", NpyArray::<Quaternion<f64>>::read(&transposed[..]).unwrap_err().to_string());
+        assert_eq!("not a .npy file", err(NpyArray::read(&c8_bytes[1..])));
+        assert_eq!(io::ErrorKind::UnexpectedEof, NpyArray::<Complex<f32>>::read(&c8_bytes[..c8_bytes.len() - 1]).unwrap_err().kind());
+        let mut bad = Vec::from(c8_bytes);
+        bad[20] = b'?';
+        assert_eq!("malformed .npy header", NpyArray::<Complex<f32>>::read(&bad[..]).unwrap_err().to_string());
+        bad[20] = b'\'';
+        let at = bad.windows(5).position(|w| w == b"'<c8'").unwrap();
+        bad[at..at + 5].copy_from_slice(b"''   ");
+        assert_eq!("dtype '' is not 'c8'", NpyArray::<Complex<f32>>::read(&bad[..]).unwrap_err().to_string());
+        let a = NpyArray { shape: vec![2], fortran_order: false, data: vec![Complex::<f32>::zero] };
+        assert_eq!(io::ErrorKind::InvalidInput, a.write(io::sink()).unwrap_err().kind());
+    }
 }
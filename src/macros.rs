@@ -0,0 +1,81 @@
+//! Literal macros
+
+/// Complex number from a sum of terms, e.g. `c!(3 + 4 i)` or `c!(-i)`
+///
+/// Each term is a literal or parenthesized expression, followed by `i` for the imaginary part, or just `i`.
+/// Terms not given are zero.
+#[macro_export]
+macro_rules! c {
+    ($($t: tt)+) => { $crate::__hypercomplex_terms!(@first c [] [] [] [] $($t)+) };
+}
+
+/// Quaternion from a sum of terms, e.g. `q!(1 + 2 i - 3 j + 4 k)` or `q!(j)`
+///
+/// Each term is a literal or parenthesized expression, followed by `i`, `j` or `k` for the vector parts, or just a unit.
+/// Terms not given are zero.
+#[macro_export]
+macro_rules! q {
+    ($($t: tt)+) => { $crate::__hypercomplex_terms!(@first q [] [] [] [] $($t)+) };
+}
+
+/// Any nesting of `Complex` from its flat list of components, in canonical basis order, e.g. `hc![1, 2, 3, 4]` for `1 + 2i + 3j + 4k`
+///
+/// The type comes from the context; if the number of components is not its `Hypercomplex::DIM`, this fails to compile.
+#[macro_export]
+macro_rules! hc {
+    ($($x: expr),+ $(,)*) => { <_ as $crate::Hypercomplex>::from_array([$($x),+]) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hypercomplex_terms {
+    (@first $m: ident $r: tt $i: tt $j: tt $k: tt - $($t: tt)+) => { $crate::__hypercomplex_terms!(@term $m $r $i $j $k [-] $($t)+) };
+    (@first $m: ident $r: tt $i: tt $j: tt $k: tt + $($t: tt)+) => { $crate::__hypercomplex_terms!(@term $m $r $i $j $k [] $($t)+) };
+    (@first $m: ident $r: tt $i: tt $j: tt $k: tt $($t: tt)+) => { $crate::__hypercomplex_terms!(@term $m $r $i $j $k [] $($t)+) };
+
+    (@next $m: ident $r: tt $i: tt $j: tt $k: tt) => { $crate::__hypercomplex_terms!(@build $m $r $i $j $k) };
+    (@next $m: ident $r: tt $i: tt $j: tt $k: tt - $($t: tt)+) => { $crate::__hypercomplex_terms!(@term $m $r $i $j $k [-] $($t)+) };
+    (@next $m: ident $r: tt $i: tt $j: tt $k: tt + $($t: tt)+) => { $crate::__hypercomplex_terms!(@term $m $r $i $j $k [] $($t)+) };
+
+    (@term $m: ident $r: tt $i: tt $j: tt $k: tt [$($s: tt)*] $c: literal $($t: tt)*) => {
+        $crate::__hypercomplex_terms!(@unit $m $r $i $j $k ($($s)* $c) $($t)*)
+    };
+    (@term $m: ident $r: tt $i: tt $j: tt $k: tt [$($s: tt)*] ($c: expr) $($t: tt)*) => {
+        $crate::__hypercomplex_terms!(@unit $m $r $i $j $k ($($s)* ($c)) $($t)*)
+    };
+    (@term $m: ident $r: tt $i: tt $j: tt $k: tt [] $u: ident $($t: tt)*) => {
+        $crate::__hypercomplex_terms!(@unit $m $r $i $j $k ($crate::__one()) $u $($t)*)
+    };
+    (@term $m: ident $r: tt $i: tt $j: tt $k: tt [-] $u: ident $($t: tt)*) => {
+        $crate::__hypercomplex_terms!(@unit $m $r $i $j $k ($crate::__minus_one()) $u $($t)*)
+    };
+
+    (@unit $m: ident $r: tt [$($i: tt)*] $j: tt $k: tt $c: tt i $($t: tt)*) => { $crate::__hypercomplex_terms!(@next $m $r [$($i)* $c] $j $k $($t)*) };
+    (@unit $m: ident $r: tt $i: tt [$($j: tt)*] $k: tt $c: tt j $($t: tt)*) => { $crate::__hypercomplex_terms!(@next $m $r $i [$($j)* $c] $k $($t)*) };
+    (@unit $m: ident $r: tt $i: tt $j: tt [$($k: tt)*] $c: tt k $($t: tt)*) => { $crate::__hypercomplex_terms!(@next $m $r $i $j [$($k)* $c] $($t)*) };
+    (@unit $m: ident [$($r: tt)*] $i: tt $j: tt $k: tt $c: tt $($t: tt)*) => { $crate::__hypercomplex_terms!(@next $m [$($r)* $c] $i $j $k $($t)*) };
+
+    (@build c [$($r: tt)*] [$($i: tt)*] [] []) => {
+        $crate::Complex::<_>::from_rect($crate::__hypercomplex_terms!(@sum $($r)*), $crate::__hypercomplex_terms!(@sum $($i)*))
+    };
+    (@build c $r: tt $i: tt $j: tt $k: tt) => { compile_error!("`j` and `k` are not complex units") };
+    (@build q [$($r: tt)*] [$($i: tt)*] [$($j: tt)*] [$($k: tt)*]) => {
+        $crate::quaternion($crate::__hypercomplex_terms!(@sum $($r)*), $crate::__hypercomplex_terms!(@sum $($i)*),
+                           $crate::__hypercomplex_terms!(@sum $($j)*), $crate::__hypercomplex_terms!(@sum $($k)*))
+    };
+
+    (@sum) => { $crate::__zero() };
+    (@sum $c: tt $($cs: tt)*) => { $c $(+ $cs)* };
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn __zero<A: ::idem::Zero>() -> A { A::zero }
+
+#[doc(hidden)]
+#[inline]
+pub const fn __one<A: ::idem::One>() -> A { A::one }
+
+#[doc(hidden)]
+#[inline]
+pub fn __minus_one<A: ::idem::One + ::core::ops::Neg<Output = A>>() -> A { -A::one }
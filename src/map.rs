@@ -0,0 +1,44 @@
+use { Complex, Hypercomplex, SelfConjugate, Sign, from_rect };
+
+/// Apply functions to every base scalar, through all levels of nesting
+pub trait MapScalar<B>: Hypercomplex {
+    /// `Self` with base scalar `B`
+    type Output;
+
+    fn map<F: FnMut(Self::Scalar) -> B>(self, f: F) -> Self::Output;
+
+    fn zip_with<F: FnMut(Self::Scalar, Self::Scalar) -> B>(self, other: Self, f: F) -> Self::Output;
+}
+
+impl<S: Sign<A> + Sign<A::Output>, A: MapScalar<B>, B> MapScalar<B> for Complex<A, S> {
+    type Output = Complex<A::Output, S>;
+
+    #[inline] fn map<F: FnMut(A::Scalar) -> B>(self, mut f: F) -> Self::Output {
+        let Complex(_, a, b) = self;
+        from_rect(a.map(&mut f), b.map(&mut f))
+    }
+
+    #[inline] fn zip_with<F: FnMut(A::Scalar, A::Scalar) -> B>(self, Complex(_, c, d): Self, mut f: F) -> Self::Output {
+        let Complex(_, a, b) = self;
+        from_rect(a.zip_with(c, &mut f), b.zip_with(d, &mut f))
+    }
+}
+
+impl<A: MapScalar<B>, B> MapScalar<B> for SelfConjugate<A> {
+    type Output = SelfConjugate<A::Output>;
+
+    #[inline] fn map<F: FnMut(A::Scalar) -> B>(self, f: F) -> Self::Output { SelfConjugate(self.0.map(f)) }
+
+    #[inline] fn zip_with<F: FnMut(A::Scalar, A::Scalar) -> B>(self, SelfConjugate(b): Self, f: F) -> Self::Output {
+        SelfConjugate(self.0.zip_with(b, f))
+    }
+}
+
+macro_rules! impl_MapScalar_scalar {
+    ($($t: ty),*) => ($(impl<B> MapScalar<B> for $t {
+        type Output = B;
+        #[inline] fn map<F: FnMut($t) -> B>(self, mut f: F) -> B { f(self) }
+        #[inline] fn zip_with<F: FnMut($t, $t) -> B>(self, other: $t, mut f: F) -> B { f(self, other) }
+    })*);
+}
+impl_MapScalar_scalar!(f32, f64, isize, i8, i16, i32, i64);
@@ -0,0 +1,63 @@
+use core::ops::*;
+
+use { Complex, Conjugable, Float, SelfConjugate, Sign, from_rect };
+
+/// Quadratic form `x x*`, taken to the base scalar type
+///
+/// For split and dual algebras this is indefinite or degenerate, so `recip` is undefined wherever `norm_sqr` is zero.
+pub trait Norm: Sized {
+    /// The base scalar type
+    type Real;
+
+    fn norm_sqr(&self) -> Self::Real;
+
+    /// Divide every base component by `k`.
+    fn unscale(self, k: &Self::Real) -> Self;
+
+    #[inline]
+    fn norm(&self) -> Self::Real where Self::Real: Float { self.norm_sqr().sqrt() }
+
+    /// Multiplicative inverse, `x* / (x x*)`
+    #[inline]
+    fn recip(self) -> Self where Self: Conjugable {
+        let n = self.norm_sqr();
+        self.conjugate().unscale(&n)
+    }
+
+    #[inline]
+    fn normalize(self) -> Self where Self::Real: Float {
+        let n = self.norm();
+        self.unscale(&n)
+    }
+}
+
+impl<S: Sign<A> + Sign<A::Real>, A: Norm> Norm for Complex<A, S> where A::Real: Sub<Output = A::Real> {
+    type Real = A::Real;
+
+    #[inline] fn norm_sqr(&self) -> A::Real {
+        let Complex(_, a, b) = self;
+        a.norm_sqr() - S::sign(b.norm_sqr())
+    }
+
+    #[inline] fn unscale(self, k: &A::Real) -> Self {
+        let Complex(_, a, b) = self;
+        from_rect(a.unscale(k), b.unscale(k))
+    }
+}
+
+impl<A: Clone + Mul<Output = A> + Div<Output = A>> Norm for SelfConjugate<A> {
+    type Real = Self;
+
+    #[inline] fn norm_sqr(&self) -> Self { SelfConjugate(self.0.clone() * self.0.clone()) }
+
+    #[inline] fn unscale(self, SelfConjugate(k): &Self) -> Self { SelfConjugate(self.0 / k.clone()) }
+}
+
+macro_rules! impl_Norm_scalar {
+    ($($t: ty),*) => ($(impl Norm for $t {
+        type Real = $t;
+        #[inline] fn norm_sqr(&self) -> $t { self*self }
+        #[inline] fn unscale(self, k: &$t) -> $t { self/k }
+    })*);
+}
+impl_Norm_scalar!(f32, f64, isize, i8, i16, i32, i64);
@@ -0,0 +1,223 @@
+//! NumPy `.npy` files of complex numbers and quaternions
+//!
+//! `Complex<f32>` and `Complex<f64>` are the dtypes `c8` and `c16`; `Quaternion<f32>` and `Quaternion<f64>` are arrays of `f4` and `f8` whose last axis has length 4, for the components in order `1, i, j, k`.
+//! Either byte order is read; files are written little-endian, with the header as NumPy writes it.
+
+use std::{ format, iter, str };
+use std::io::{ self, Read, Write };
+use std::string::String;
+use std::vec::Vec;
+use idem::Zero;
+
+use { Complex, Quaternion };
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Room left in the header to grow the first axis in place, as by NumPy
+const GROWTH_AXIS_MAX_DIGITS: usize = 21;
+
+/// Element type of a `.npy` array
+pub trait NpyElement: Zero + Clone {
+    /// `descr` of the dtype, without the byte order, e.g. `c8`
+    const DESCR: &'static str;
+    /// Length of the last axis of the file array, which holds the components of each element, if any
+    const AXIS: Option<usize>;
+    /// Bytes per element
+    const BYTES: usize;
+
+    fn decode(bytes: &[u8], big_endian: bool, out: &mut [Self]);
+    fn encode(xs: &[Self], out: &mut [u8]);
+}
+
+macro_rules! impl_NpyElement {
+    ($($t: ty, $descr: expr, $axis: expr);*) => ($(impl NpyElement for $t {
+        const DESCR: &'static str = $descr;
+        const AXIS: Option<usize> = $axis;
+        const BYTES: usize = <$t>::BYTES;
+
+        #[inline] fn decode(bytes: &[u8], big_endian: bool, out: &mut [Self]) {
+            if big_endian { <$t>::read_be_bytes(bytes, out) } else { <$t>::read_le_bytes(bytes, out) }.expect("wrong byte length")
+        }
+
+        #[inline] fn encode(xs: &[Self], out: &mut [u8]) { <$t>::write_le_bytes(xs, out).expect("wrong byte length") }
+    })*);
+}
+impl_NpyElement!(Complex<f32>, "c8", None; Complex<f64>, "c16", None; Quaternion<f32>, "f4", Some(4); Quaternion<f64>, "f8", Some(4));
+
+/// An array read from or to be written to a `.npy` file
+///
+/// `shape` is that of the array of `T`, so without the last axis of components of quaternions.
+/// `data` is in the order of the file: last index fastest, or first index fastest if `fortran_order`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpyArray<T> {
+    pub shape: Vec<usize>,
+    pub fortran_order: bool,
+    pub data: Vec<T>,
+}
+
+impl<T: NpyElement> NpyArray<T> {
+    pub fn read<R: Read>(mut r: R) -> io::Result<Self> {
+        let mut prefix = [0; 8];
+        r.read_exact(&mut prefix)?;
+        if MAGIC != &prefix[..6] { return Err(invalid_data("not a .npy file")) }
+        let len = match prefix[6] {
+            1 => { let mut b = [0; 2]; r.read_exact(&mut b)?; u16::from_le_bytes(b) as usize },
+            2 | 3 => { let mut b = [0; 4]; r.read_exact(&mut b)?; u32::from_le_bytes(b) as usize },
+            _ => return Err(invalid_data("unsupported .npy version")),
+        };
+        let mut header = Vec::new();
+        r.by_ref().take(len as u64).read_to_end(&mut header)?;
+        if header.len() != len { return Err(io::ErrorKind::UnexpectedEof.into()) }
+        let (descr, fortran_order, mut shape) = str::from_utf8(&header).ok().and_then(parse_header)
+            .ok_or_else(|| invalid_data("malformed .npy header"))?;
+
+        let big_endian = match descr.as_bytes().split_first() {
+            Some((b'<', d)) if d == T::DESCR.as_bytes() => false,
+            Some((b'>', d)) if d == T::DESCR.as_bytes() => true,
+            _ => return Err(invalid_data(&format!("dtype '{}' is not '{}'", descr, T::DESCR))),
+        };
+        if let Some(k) = T::AXIS {
+            if shape.pop() != Some(k) { return Err(invalid_data(&format!("last axis of shape not of length {}", k))) }
+        }
+        let n = shape.iter().try_fold(1usize, |n, &m| n.checked_mul(m)).ok_or_else(|| invalid_data("array too large"))?;
+        let size = n.checked_mul(T::BYTES).ok_or_else(|| invalid_data("array too large"))?;
+
+        let mut bytes = Vec::new();
+        r.take(size as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != size { return Err(io::ErrorKind::UnexpectedEof.into()) }
+        if let (true, Some(k)) = (fortran_order, T::AXIS) { bytes = transpose(&bytes, k, n, T::BYTES / k) }
+        let mut data = std::vec![T::zero; n];
+        T::decode(&bytes, big_endian, &mut data);
+        Ok(NpyArray { shape, fortran_order, data })
+    }
+
+    pub fn write<W: Write>(&self, mut w: W) -> io::Result<()> {
+        if self.shape.iter().try_fold(1usize, |n, &m| n.checked_mul(m)) != Some(self.data.len()) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "shape does not match data length"));
+        }
+        let mut shape = self.shape.clone();
+        shape.extend(T::AXIS);
+
+        let mut header = format!("{{'descr': '<{}', 'fortran_order': {}, 'shape': {}, }}",
+                                 T::DESCR, if self.fortran_order { "True" } else { "False" }, shape_repr(&shape));
+        if let Some(&m) = if self.fortran_order { shape.last() } else { shape.first() } {
+            header.extend(iter::repeat_n(' ', GROWTH_AXIS_MAX_DIGITS.saturating_sub(format!("{}", m).len())));
+        }
+        // Pad with spaces and a newline so that the data are aligned to 64 bytes, with the 2-byte length of version 1.0 if it fits.
+        let pad = |header: &mut String, prefix: usize| {
+            header.extend(iter::repeat_n(' ', 64 - (header.len() + 1 + prefix) % 64));
+            header.push('\n');
+        };
+        let mut prefix = Vec::from(MAGIC);
+        let mut padded = header.clone();
+        pad(&mut padded, 10);
+        if padded.len() <= u16::MAX as usize {
+            prefix.extend_from_slice(&[1, 0]);
+            prefix.extend_from_slice(&(padded.len() as u16).to_le_bytes());
+        } else {
+            padded = header;
+            pad(&mut padded, 12);
+            prefix.extend_from_slice(&[2, 0]);
+            prefix.extend_from_slice(&(padded.len() as u32).to_le_bytes());
+        }
+        let header = padded;
+
+        let n = self.data.len();
+        let mut bytes = std::vec![0; n * T::BYTES];
+        T::encode(&self.data, &mut bytes);
+        if let (true, Some(k)) = (self.fortran_order, T::AXIS) { bytes = transpose(&bytes, n, k, T::BYTES / k) }
+        w.write_all(&prefix)?;
+        w.write_all(header.as_bytes())?;
+        w.write_all(&bytes)
+    }
+}
+
+#[inline]
+fn invalid_data(msg: &str) -> io::Error { io::Error::new(io::ErrorKind::InvalidData, msg) }
+
+/// Transpose a row-major `rows × cols` matrix of `size`-byte scalars
+fn transpose(bytes: &[u8], rows: usize, cols: usize, size: usize) -> Vec<u8> {
+    let mut out = std::vec![0; bytes.len()];
+    for (i, row) in bytes.chunks_exact(cols * size).enumerate() {
+        for (j, x) in row.chunks_exact(size).enumerate() {
+            out[(j * rows + i) * size..][..size].copy_from_slice(x);
+        }
+    }
+    out
+}
+
+/// Python `repr` of a shape tuple
+fn shape_repr(shape: &[usize]) -> String {
+    match shape {
+        [] => String::from("()"),
+        [m] => format!("({},)", m),
+        _ => format!("({})", shape.iter().map(|m| format!("{}", m)).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+/// Parse the header dictionary into `(descr, fortran_order, shape)`
+fn parse_header(s: &str) -> Option<(String, bool, Vec<usize>)> {
+    let mut p = Parser(s.trim_end().as_bytes());
+    let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+    p.expect(b'{')?;
+    while !p.eat(b'}') {
+        let key = p.string()?;
+        p.expect(b':')?;
+        match key {
+            "descr" if descr.is_none() => descr = Some(String::from(p.string()?)),
+            "fortran_order" if fortran_order.is_none() => fortran_order = Some(p.boolean()?),
+            "shape" if shape.is_none() => shape = Some(p.tuple()?),
+            _ => return None,
+        }
+        if !p.eat(b',') { p.expect(b'}')?; break }
+    }
+    if !p.0.is_empty() { return None }
+    Some((descr?, fortran_order?, shape?))
+}
+
+struct Parser<'a>(&'a [u8]);
+
+impl<'a> Parser<'a> {
+    fn skip_space(&mut self) {
+        while let Some((&b' ', s)) = self.0.split_first() { self.0 = s }
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        self.skip_space();
+        match self.0.split_first() {
+            Some((&d, s)) if c == d => { self.0 = s; true },
+            _ => false,
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> { if self.eat(c) { Some(()) } else { None } }
+
+    fn string(&mut self) -> Option<&'a str> {
+        self.skip_space();
+        let (&q, s) = self.0.split_first().filter(|&(&q, _)| q == b'\'' || q == b'"')?;
+        let n = s.iter().position(|&c| c == q)?;
+        self.0 = &s[n + 1..];
+        str::from_utf8(&s[..n]).ok()
+    }
+
+    fn boolean(&mut self) -> Option<bool> {
+        self.skip_space();
+        for &(word, b) in &[(&b"True"[..], true), (&b"False"[..], false)] {
+            if self.0.starts_with(word) { self.0 = &self.0[word.len()..]; return Some(b) }
+        }
+        None
+    }
+
+    fn tuple(&mut self) -> Option<Vec<usize>> {
+        self.expect(b'(')?;
+        let mut v = Vec::new();
+        while !self.eat(b')') {
+            self.skip_space();
+            let n = self.0.iter().position(|c| !c.is_ascii_digit()).unwrap_or(self.0.len());
+            v.push(str::from_utf8(&self.0[..n]).ok()?.parse().ok()?);
+            self.0 = &self.0[n..];
+            if !self.eat(b',') { self.expect(b')')?; break }
+        }
+        Some(v)
+    }
+}
@@ -0,0 +1,18 @@
+//! Arbitrary-precision scalars from `num-bigint` and `num-rational`
+//!
+//! The orphan rule keeps other crates from making these `Conjugable`, which `Complex` needs of its scalar to multiply and divide, so we do it here.
+
+#[cfg(feature = "num-bigint")] use num_bigint::BigInt;
+#[cfg(feature = "num-rational")] use num_rational::Ratio;
+
+use Conjugable;
+
+#[cfg(feature = "num-bigint")]
+impl Conjugable for BigInt {
+    #[inline] fn conjugate(self) -> Self { self }
+}
+
+#[cfg(feature = "num-rational")]
+impl<T> Conjugable for Ratio<T> {
+    #[inline] fn conjugate(self) -> Self { self }
+}
@@ -0,0 +1,155 @@
+//! Parsing what `Display` writes, e.g. `3-4i`, `1 + 2i - 3j + 4k` or `1*e0-2*e7`
+//!
+//! A value is a sum of terms, each a coefficient, a basis label, or a coefficient then a label, optionally separated by `*`.
+//! The labels are those `Display` writes by default, `e0`, `e1`, … in any dimension, and otherwise `i`, `j` and `k` for `e1`, `e2` and `e3`, save that in two dimensions `j` and `ε` are also `e1`.
+//! A coefficient without a label is the real part; a label without a coefficient has coefficient 1.
+//! Terms may appear in any order; those not given are zero.
+//!
+//! Note that `2e1` is the number 20: write `2*e1` for twice `e1`.
+
+use core::fmt;
+use core::iter;
+use core::ops::Neg;
+use core::str::FromStr;
+use idem::{ One, Zero };
+
+use display::default_label;
+use { Complex, Hypercomplex, Sign };
+
+/// Error parsing a hypercomplex number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseHypercomplexError {
+    /// No terms
+    Empty,
+    /// Unexpected character, at this byte offset
+    UnexpectedToken(usize),
+    /// Coefficient the scalar type could not parse, at this byte offset
+    InvalidNumber(usize),
+    /// Basis element with this index given twice
+    DuplicateBasis(usize),
+    /// Basis element with this index, not less than the dimension
+    WrongDimension(usize),
+}
+
+impl fmt::Display for ParseHypercomplexError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        use self::ParseHypercomplexError::*;
+        match *self {
+            Empty => fmt.write_str("no terms"),
+            UnexpectedToken(pos) => write!(fmt, "unexpected token at byte {}", pos),
+            InvalidNumber(pos) => write!(fmt, "invalid number at byte {}", pos),
+            DuplicateBasis(n) => write!(fmt, "duplicate term in e{}", n),
+            WrongDimension(n) => write!(fmt, "no basis element e{} in this dimension", n),
+        }
+    }
+}
+
+struct Parser<'a> { s: &'a str, pos: usize }
+
+impl<'a> Parser<'a> {
+    #[inline] fn rest(&self) -> &'a str { &self.s[self.pos..] }
+
+    #[inline] fn peek(&self) -> Option<u8> { self.s.as_bytes().get(self.pos).cloned() }
+
+    fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) { self.pos += 1; true } else { false }
+    }
+
+    fn skip_whitespace(&mut self) { self.pos = self.s.len() - self.rest().trim_start().len(); }
+
+    fn skip_digits(&mut self) -> usize {
+        let n = self.rest().bytes().take_while(u8::is_ascii_digit).count();
+        self.pos += n;
+        n
+    }
+
+    /// Unsigned number, as Rust writes it
+    fn number(&mut self) -> Option<(usize, usize)> {
+        let start = self.pos;
+        for word in ["infinity", "inf", "nan"] {
+            if self.rest().get(..word.len()).is_some_and(|w| w.eq_ignore_ascii_case(word)) {
+                self.pos += word.len();
+                return Some((start, self.pos));
+            }
+        }
+        let mut digits = self.skip_digits();
+        if self.eat(b'.') { digits += self.skip_digits(); }
+        if digits == 0 { self.pos = start; return None }
+        let mantissa = self.pos;
+        if self.eat(b'e') || self.eat(b'E') {
+            if !self.eat(b'+') { self.eat(b'-'); }
+            if self.skip_digits() == 0 { self.pos = mantissa; }
+        }
+        Some((start, self.pos))
+    }
+
+    /// Basis label of `T`, as the index of its element
+    fn label<T: Hypercomplex>(&mut self) -> Result<Option<usize>, ParseHypercomplexError> {
+        let dim = T::DIM;
+        let rest = self.rest();
+        let default = (1..dim).map(|n| (n, default_label::<T>(n)))
+                              .filter(|(_, l)| !l.starts_with('e') && rest.starts_with(&**l)).max_by_key(|(_, l)| l.len());
+        if let Some((n, l)) = default { self.pos += l.len(); return Ok(Some(n)) }
+        let n = match self.peek() {
+            Some(b'i') => 1,
+            Some(b'j') => if dim == 2 { 1 } else { 2 },
+            Some(b'k') => 3,
+            Some(b'e') => {
+                let start = self.pos;
+                self.pos += 1;
+                if self.skip_digits() == 0 { return Err(ParseHypercomplexError::UnexpectedToken(start)) }
+                return self.s[start+1..self.pos].parse().map(Some).map_err(|_| ParseHypercomplexError::WrongDimension(usize::MAX))
+            },
+            _ if dim == 2 && self.rest().starts_with('ε') => { self.pos += 'ε'.len_utf8(); return Ok(Some(1)) },
+            _ => return Ok(None),
+        };
+        self.pos += 1;
+        Ok(Some(n))
+    }
+}
+
+/// Parse `s` as a sum of terms in `T`.
+fn parse<T: Hypercomplex>(s: &str) -> Result<T, ParseHypercomplexError> where T::Scalar: FromStr + Zero + One + Neg<Output = T::Scalar> {
+    use self::ParseHypercomplexError::*;
+    const { assert!(T::DIM <= 128, "too many dimensions") };
+
+    let mut x = T::from_components(&mut iter::repeat_with(|| T::Scalar::zero)).expect("endless components");
+    let mut seen = 0u128;
+    let mut p = Parser { s, pos: 0 };
+    loop {
+        p.skip_whitespace();
+        if p.peek().is_none() { return if seen == 0 { Err(Empty) } else { Ok(x) } }
+
+        let sign = p.pos;
+        let negative = p.eat(b'-');
+        if !negative && !p.eat(b'+') && seen != 0 { return Err(UnexpectedToken(p.pos)) }
+        p.skip_whitespace();
+        let number = p.number();
+        p.skip_whitespace();
+        let star = number.is_some() && p.eat(b'*');
+        if star { p.skip_whitespace(); }
+        let label = p.label::<T>()?;
+        if number.is_none() && label.is_none() || star && label.is_none() { return Err(UnexpectedToken(p.pos)) }
+
+        let n = label.unwrap_or(0);
+        if n >= T::DIM { return Err(WrongDimension(n)) }
+        if seen & 1 << n != 0 { return Err(DuplicateBasis(n)) }
+        seen |= 1 << n;
+
+        *x.component_mut(n) = match number {
+            // Parse the sign with the number where we can, for the least integers.
+            Some((start, end)) if negative && start == sign + 1 => s[sign..end].parse().map_err(|_| InvalidNumber(start))?,
+            Some((start, end)) => {
+                let a: T::Scalar = s[start..end].parse().map_err(|_| InvalidNumber(start))?;
+                if negative { -a } else { a }
+            },
+            None => if negative { -T::Scalar::one } else { T::Scalar::one },
+        };
+    }
+}
+
+impl<S: Sign<A>, A> FromStr for Complex<A, S> where Self: Hypercomplex, <Self as Hypercomplex>::Scalar: FromStr + Zero + One + Neg<Output = <Self as Hypercomplex>::Scalar> {
+    type Err = ParseHypercomplexError;
+
+    #[inline] fn from_str(s: &str) -> Result<Self, ParseHypercomplexError> { parse(s) }
+}
@@ -0,0 +1,114 @@
+//! Polar forms
+//!
+//! Zeros, infinities and NaNs are handled explicitly: a zero modulus times an infinite or NaN trigonometric factor is zero, and an infinite modulus times a zero factor is zero, so e.g. `from_polar(∞, 0) = ∞ + 0i`.
+
+use typenum::consts::P1;
+
+use { Complex, Float, Quaternion, from_rect, quaternion };
+
+/// `r t`, but zero if `t` is zero, even if `r` is infinite
+#[inline]
+fn scale<F: Float>(r: F, t: F) -> F {
+    if t == F::zero { if r < F::zero { -t } else { t } } else { r * t }
+}
+
+/// `from_polar` with `(cos θ, sin θ)` given
+#[inline]
+fn from_polar_with<F: Float>(r: F, theta: F, (c, s): (F, F)) -> (F, F) {
+    let nan = F::NAN;
+    if r.is_nan() || theta.is_nan() { (nan, nan) }
+    else if r == F::zero { (r, F::zero) }
+    else if theta.is_infinite() { (nan, nan) }
+    else { (scale(r, c), scale(r, s)) }
+}
+
+impl<F: Float> Complex<F> {
+    /// `|z|`, by `hypot`, so it overflows only if the result does; infinite if either component is infinite, even if the other is NaN
+    #[inline] pub fn abs(&self) -> F { self.re().hypot(*self.im()) }
+
+    /// Argument in `[-π, π]`, by `atan2`, so the signs of zeros pick the side of the branch cut
+    #[inline] pub fn arg(&self) -> F { self.im().atan2(*self.re()) }
+
+    /// `(|z|, arg z)`
+    #[inline] pub fn into_polar(self) -> (F, F) { (self.abs(), self.arg()) }
+
+    /// `r (cos θ + i sin θ)`
+    ///
+    /// Zero if `r` is zero; NaN if either argument is NaN, or `θ` is infinite and `r` is not zero.
+    #[inline] pub fn from_polar(r: F, theta: F) -> Self {
+        let (a, b) = from_polar_with(r, theta, (theta.cos(), theta.sin()));
+        from_rect(a, b)
+    }
+}
+
+impl<F: Float> Complex<F, P1> {
+    /// `√|(a + b)(a - b)|`, with the square roots taken apart where the product would overflow or underflow
+    #[inline] pub fn modulus(&self) -> F {
+        let (&a, &b) = self.as_rect();
+        let (p, q) = ((a + b).abs(), (a - b).abs());
+        let m = p * q;
+        if m.is_infinite() && !p.is_infinite() && !q.is_infinite() || m == F::zero && p != F::zero && q != F::zero {
+            p.sqrt() * q.sqrt()
+        } else { m.sqrt() }
+    }
+
+    /// `atanh(b/a)` if `|a| ≥ |b|`, else `atanh(a/b)`; infinite on the light cone `|a| = |b|`, but zero at zero
+    #[inline] pub fn rapidity(&self) -> F {
+        let (&a, &b) = self.as_rect();
+        if a == F::zero && b == F::zero { F::zero }
+        else if a.abs() >= b.abs() { (b/a).atanh() }
+        else { (a/b).atanh() }
+    }
+
+    /// `(ρ, φ)` such that `x = ρ (cosh φ + j sinh φ)`, where `ρ` has the sign of `a`
+    ///
+    /// This form exists only where `|a| > |b|`, and at zero; elsewhere, including where either component is NaN, this is `None`.
+    #[inline] pub fn into_polar(self) -> Option<(F, F)> {
+        let (&a, &b) = self.as_rect();
+        if a == F::zero && b == F::zero { Some((F::zero, F::zero)) }
+        else if a.abs() > b.abs() { Some((self.modulus().copysign(a), (b/a).atanh())) }
+        else { None }
+    }
+
+    /// `ρ (cosh φ + j sinh φ)`
+    ///
+    /// Zero if `ρ` is zero; NaN if either argument is NaN, or `φ` is infinite and `ρ` is not zero.
+    #[inline] pub fn from_polar(rho: F, phi: F) -> Self {
+        let (a, b) = from_polar_with(rho, phi, (phi.cosh(), phi.sinh()));
+        from_rect(a, b)
+    }
+}
+
+impl<F: Float> Quaternion<F> {
+    /// `(|q|, u, θ)` such that `q = |q| (cos θ + u sin θ)`, with `u` a unit vector and `θ` in `[0, π]`
+    ///
+    /// Where the vector part is zero, `u` is `i`, and `θ` is 0 or π by the sign of the real part.
+    /// Where the vector part is infinite, `u` is along its infinite components.
+    /// If any component is NaN, so are all.
+    ///
+    /// As a rotation, `q` turns by `2θ` about `u`.
+    pub fn into_axis_angle(self) -> (F, [F; 3], F) {
+        let (a, b) = self.into_rect();
+        let ((w, x), (y, z)) = (a.into_rect(), b.into_rect());
+        let nan = F::NAN;
+        if [w, x, y, z].iter().any(|c| c.is_nan()) { return (nan, [nan; 3], nan) }
+        let s = x.hypot(y.hypot(z));
+        let axis = if s == F::zero { [F::one, F::zero, F::zero] }
+        else if s.is_infinite() {
+            let [x, y, z] = [x, y, z].map(|c| if c.is_infinite() { F::one.copysign(c) } else { F::zero });
+            let t = x.hypot(y.hypot(z));
+            [x/t, y/t, z/t]
+        } else { [x/s, y/s, z/s] };
+        (w.hypot(s), axis, s.atan2(w))
+    }
+
+    /// `r (cos θ + u sin θ)`, where `u` should be a unit vector
+    ///
+    /// Zero if `r` is zero; NaN if any argument is NaN, or `θ` is infinite and `r` is not zero.
+    pub fn from_axis_angle(r: F, [x, y, z]: [F; 3], theta: F) -> Self {
+        let nan = F::NAN;
+        if x.is_nan() || y.is_nan() || z.is_nan() { return quaternion(nan, nan, nan, nan) }
+        let (a, b) = from_polar_with(r, theta, (theta.cos(), theta.sin()));
+        quaternion(a, scale(b, x), scale(b, y), scale(b, z))
+    }
+}
@@ -0,0 +1,66 @@
+//! Arithmetic between hypercomplex numbers and their base scalars, component-wise
+
+use core::ops::*;
+use idem::One;
+
+use { Complex, SelfConjugate, Sign, from_rect };
+
+macro_rules! impl_scalar_ops {
+    ($($t: ty),*) => ($(
+        impl<S: Sign<A>, A: Mul<$t, Output = A>> Mul<$t> for Complex<A, S> {
+            type Output = Self;
+            #[inline] fn mul(self, k: $t) -> Self {
+                let Complex(_, a, b) = self;
+                from_rect(a*k, b*k)
+            }
+        }
+
+        impl<S: Sign<A>, A: Div<$t, Output = A>> Div<$t> for Complex<A, S> {
+            type Output = Self;
+            #[inline] fn div(self, k: $t) -> Self {
+                let Complex(_, a, b) = self;
+                from_rect(a/k, b/k)
+            }
+        }
+
+        impl<S: Sign<A>, A> Mul<Complex<A, S>> for $t where Complex<A, S>: Mul<$t, Output = Complex<A, S>> {
+            type Output = Complex<A, S>;
+            #[inline] fn mul(self, z: Complex<A, S>) -> Complex<A, S> { z*self }
+        }
+
+        /// `(1 k) / z`, so by the robust division for floating-point scalars
+        impl<S: Sign<A>, A> Div<Complex<A, S>> for $t
+          where Complex<A, S>: One + Mul<$t, Output = Complex<A, S>> + Div<Output = Complex<A, S>> {
+            type Output = Complex<A, S>;
+            #[inline]
+            fn div(self, z: Complex<A, S>) -> Complex<A, S> { Complex::one * self / z }
+        }
+
+        impl<S: Sign<A>, A: MulAssign<$t>> MulAssign<$t> for Complex<A, S> {
+            #[inline] fn mul_assign(&mut self, k: $t) { self.1 *= k; self.2 *= k; }
+        }
+
+        impl<S: Sign<A>, A: DivAssign<$t>> DivAssign<$t> for Complex<A, S> {
+            #[inline] fn div_assign(&mut self, k: $t) { self.1 /= k; self.2 /= k; }
+        }
+
+        impl<A: Mul<$t, Output = A>> Mul<$t> for SelfConjugate<A> {
+            type Output = Self;
+            #[inline] fn mul(self, k: $t) -> Self { SelfConjugate(self.0*k) }
+        }
+
+        impl<A: Div<$t, Output = A>> Div<$t> for SelfConjugate<A> {
+            type Output = Self;
+            #[inline] fn div(self, k: $t) -> Self { SelfConjugate(self.0/k) }
+        }
+
+        impl<A: MulAssign<$t>> MulAssign<$t> for SelfConjugate<A> {
+            #[inline] fn mul_assign(&mut self, k: $t) { self.0 *= k }
+        }
+
+        impl<A: DivAssign<$t>> DivAssign<$t> for SelfConjugate<A> {
+            #[inline] fn div_assign(&mut self, k: $t) { self.0 /= k }
+        }
+    )*);
+}
+impl_scalar_ops!(f32, f64, isize, i8, i16, i32, i64);
@@ -0,0 +1,45 @@
+//! Serde support, as a flat sequence of components in canonical basis order
+
+use core::fmt;
+use core::iter;
+use core::marker::PhantomData;
+use serde::de::{ self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor };
+use serde::ser::{ Serialize, Serializer, SerializeTuple };
+
+use { Complex, Hypercomplex, Sign };
+
+impl<A, S: Sign<A>> Serialize for Complex<A, S> where Self: Hypercomplex, <Self as Hypercomplex>::Scalar: Serialize {
+    fn serialize<Z: Serializer>(&self, z: Z) -> Result<Z::Ok, Z::Error> {
+        let mut t = z.serialize_tuple(Self::DIM)?;
+        for c in self.components() { t.serialize_element(c)?; }
+        t.end()
+    }
+}
+
+impl<'de, A, S: Sign<A>> Deserialize<'de> for Complex<A, S> where Self: Hypercomplex, <Self as Hypercomplex>::Scalar: Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_tuple(Self::DIM, ComponentsVisitor(PhantomData))
+    }
+}
+
+struct ComponentsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Hypercomplex> Visitor<'de> for ComponentsVisitor<T> where T::Scalar: Deserialize<'de> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "a sequence of {} components", T::DIM) }
+
+    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<T, V::Error> {
+        let mut n = 0;
+        let mut err = None;
+        let x = T::from_components(&mut iter::from_fn(|| match seq.next_element() {
+            Ok(c) => { n += c.is_some() as usize; c },
+            Err(e) => { err = Some(e); None },
+        }));
+        if let Some(e) = err { return Err(e) }
+        let x = x.ok_or_else(|| de::Error::invalid_length(n, &self))?;
+        while let Some(IgnoredAny) = seq.next_element()? { n += 1 }
+        if n > T::DIM { return Err(de::Error::invalid_length(n, &self)) }
+        Ok(x)
+    }
+}
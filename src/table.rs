@@ -0,0 +1,94 @@
+//! Multiplication tables of the canonical basis
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{ Deref, Mul, Neg };
+use core::str;
+use idem::{ Zero, One };
+
+use Hypercomplex;
+
+/// `sign · e_index`, where `sign` is -1, 0 or 1; `index` is 0 if `sign` is 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignedUnit { pub sign: i8, pub index: usize }
+
+impl fmt::Display for SignedUnit {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.sign {
+            0 => fmt.pad("0"),
+            s => fmt.pad(&Label::new().push(if s > 0 { b'+' } else { b'-' }).push_basis(self.index)),
+        }
+    }
+}
+
+/// The signed Cayley table of `T`, computed by multiplying its basis elements
+///
+/// Only the algebra matters, not the scalar: `CayleyTable<Octonion<i8>>` is the octonion table.
+/// Entries are computed on demand; `Display` renders the whole table, labelling the basis `e0`, `e1`, ….
+pub struct CayleyTable<T>(PhantomData<T>);
+
+impl<T> CayleyTable<T> {
+    #[inline] pub const fn new() -> Self { CayleyTable(PhantomData) }
+}
+
+impl<T> Default for CayleyTable<T> {
+    #[inline] fn default() -> Self { Self::new() }
+}
+
+impl<T: Hypercomplex + Mul<Output = T>> CayleyTable<T> where T::Scalar: Zero + One + PartialEq + Neg<Output = T::Scalar> {
+    /// `e_m e_n`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` or `n` is not less than `T::DIM`.
+    pub fn get(&self, m: usize, n: usize) -> SignedUnit {
+        let x = T::basis(m) * T::basis(n);
+        let (one, zero) = (T::Scalar::one, T::Scalar::zero);
+        let minus_one = -T::Scalar::one;
+        let mut unit = SignedUnit { sign: 0, index: 0 };
+        for (index, a) in x.components().enumerate() {
+            if *a == zero { continue; }
+            let sign = if *a == one { 1 } else if *a == minus_one { -1 } else { panic!("basis product not a signed unit") };
+            assert_eq!(0, unit.sign, "basis product not a signed unit");
+            unit = SignedUnit { sign, index };
+        }
+        unit
+    }
+}
+
+impl<T: Hypercomplex + Mul<Output = T>> fmt::Display for CayleyTable<T> where T::Scalar: Zero + One + PartialEq + Neg<Output = T::Scalar> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let width = 1 + Label::new().push_basis(T::DIM - 1).len();
+        write!(fmt, "{:1$}", "", width - 1)?;
+        for n in 0..T::DIM { write!(fmt, " {:>1$}", &*Label::new().push_basis(n), width)?; }
+        for m in 0..T::DIM {
+            write!(fmt, "\n{:<1$}", &*Label::new().push_basis(m), width - 1)?;
+            for n in 0..T::DIM { write!(fmt, " {:>1$}", self.get(m, n), width)?; }
+        }
+        Ok(())
+    }
+}
+
+/// Fixed-capacity label buffer, as we have no allocator
+pub(crate) struct Label([u8; 24], usize);
+
+impl Label {
+    pub(crate) fn new() -> Self { Label([0; 24], 0) }
+
+    pub(crate) fn push(mut self, b: u8) -> Self { self.0[self.1] = b; self.1 += 1; self }
+
+    pub(crate) fn push_str(self, s: &str) -> Self { s.bytes().fold(self, Label::push) }
+
+    fn push_usize(self, n: usize) -> Self {
+        let l = if n >= 10 { self.push_usize(n/10) } else { self };
+        l.push(b'0' + (n % 10) as u8)
+    }
+
+    /// `e_n`
+    pub(crate) fn push_basis(self, n: usize) -> Self { self.push(b'e').push_usize(n) }
+}
+
+impl Deref for Label {
+    type Target = str;
+    fn deref(&self) -> &str { str::from_utf8(&self.0[..self.1]).expect("UTF-8 label") }
+}